@@ -18,7 +18,9 @@ use spritec::query3d::{
     GeometryQuery,
 };
 use spritec::renderer::{
+    AlphaOutput,
     Camera,
+    ColorTransfer,
     FileQuery,
     GridLayout,
     GridLayoutCell,
@@ -99,6 +101,7 @@ declare_types! {
 
             let job = RenderJob {
                 scale: NonZeroU32::new(scale).expect("Scale to be a positive integer"),
+                alpha: AlphaOutput::default(),
                 root: RenderNode::Layout(RenderLayout::Grid(GridLayout {
                     rows: unsafe { NonZeroU32::new_unchecked(1) },
                     cols: NonZeroU32::new(animation_total_steps).unwrap(),
@@ -143,6 +146,7 @@ declare_types! {
                 for animation_cur_step in 0..animation_total_steps {
                     jobs.push(RenderJob {
                         scale: NonZeroU32::new(scale).expect("Scale is not NonZeroU32"),
+                        alpha: AlphaOutput::default(),
                         root: RenderNode::RenderedImage(describe_sprite(
                                 file.clone(),
                                 width,
@@ -215,6 +219,7 @@ declare_types! {
                 for animation_cur_step in 0..animation_total_steps {
                     let job = RenderJob {
                         scale: NonZeroU32::new(scale).expect("Scale to be a positive integer"),
+                        alpha: AlphaOutput::default(),
                         root: RenderNode::RenderedImage(describe_sprite(
                                 file.clone(),
                                 width,
@@ -290,6 +295,7 @@ declare_types! {
             let image = cx.borrow_mut(&mut this, |mut spritec| {
                 RenderJob {
                     scale: unsafe { NonZeroU32::new_unchecked(1) },
+                    alpha: AlphaOutput::default(),
                     root: RenderNode::RenderedImage(sprite),
                 }.execute(&mut spritec.ctx).expect("Sprite creation failed")
             });
@@ -337,8 +343,9 @@ fn describe_sprite(
         },
         camera: RenderCamera::Camera(camera),
         lights: RenderLights::Lights(lights),
+        max_lights: None,
         ambient_light: Rgb::white() * 0.5,
-        geometry: FileQuery {
+        geometry: vec![FileQuery {
             query: GeometryQuery {
                 models: GeometryFilter::all_in_default_scene(),
                 animation: animation_name.as_ref().map(|name| AnimationQuery {
@@ -347,14 +354,19 @@ fn describe_sprite(
                         start_time: Milliseconds::from_sec(0.0),
                         weight: get_weight(animation_cur_step, animation_total_steps),
                     },
+                    layers: Vec::new(),
+                    root_motion: None,
                 }),
             },
             file,
-        },
+        }.into()],
         outline: Outline {
             thickness: 0.0,
             color: Rgba::black(),
         },
+        shadows: None,
+        highlights: Default::default(),
+        color_transfer: ColorTransfer::Srgb,
     }
 }
 
@@ -1,6 +1,7 @@
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::num::NonZeroUsize;
 use std::error::Error;
 
 use structopt::{
@@ -23,15 +24,66 @@ use spritec::config::TaskConfig;
 #[structopt(author = "The ProtoArt Team <https://protoart.me>")]
 #[structopt(global_settings = &[ColoredHelp, DontCollapseArgsInUsage, ArgRequiredElseHelp])]
 pub struct AppArgs {
+    #[structopt(flatten)]
+    pub config: ConfigArgs,
+    /// Run every task even if some of them fail, then print a summary of all the tasks
+    #[structopt(short = "k", long)]
+    pub keep_going: bool,
+    /// Write a report of every task that was run to the given path as JSON
+    #[structopt(long, name = "report-file", parse(from_os_str))]
+    pub report: Option<PathBuf>,
+    /// The number of tasks to render at the same time, each on its own thread
+    ///
+    /// This mainly helps when rendering happens on the CPU (e.g. with software rasterization).
+    /// Each thread opens its own copy of every model it renders. Not supported on macOS.
+    #[structopt(short, long, default_value = "1")]
+    pub jobs: NonZeroUsize,
+    #[structopt(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    /// Checks a configuration file for problems without generating any images
+    ///
+    /// Every path is resolved and every model is opened to make sure that all of the animations,
+    /// cameras and scenes in the configuration file exist. All problems found are reported at once.
+    Check(ConfigArgs),
+    /// Lists the scenes, nodes, animations, cameras, lights and materials in a model file
+    Inspect(InspectArgs),
+}
+
+#[derive(Debug, StructOpt)]
+pub struct InspectArgs {
+    /// Path to the glTF or OBJ file to inspect
+    #[structopt(name = "model-file", parse(from_os_str))]
+    pub model_path: PathBuf,
+    /// Print the information as JSON instead of in a human readable format
+    #[structopt(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct ConfigArgs {
     /// Path to the configuration file to execute tasks from
     #[structopt(name = "config-file", default_value = "spritec.toml", parse(from_os_str))]
     config_path: PathBuf,
 }
 
-impl AppArgs {
+impl ConfigArgs {
+    /// Returns the path to the configuration file
+    pub fn path(&self) -> &Path {
+        &self.config_path
+    }
+
+    /// Reads the contents of the configuration file
+    pub fn read_config(&self) -> Result<String, io::Error> {
+        fs::read_to_string(&self.config_path)
+    }
+
     /// Loads the configuration file provided as an argument
     pub fn load_config(&self) -> Result<TaskConfig, Box<dyn Error>> {
-        Ok(toml::from_str(&fs::read_to_string(&self.config_path)?)?)
+        Ok(toml::from_str(&self.read_config()?)?)
     }
 
     /// Determines the base directory of the configuration file, used to resolve all paths within
@@ -0,0 +1,50 @@
+use std::path::Path;
+
+use terminator::Terminator;
+use spritec::{
+    config::TaskConfig,
+    check::{self, SourceMap, SourceLocation},
+};
+
+use crate::args::ConfigArgs;
+
+/// Checks the given configuration file, printing every problem found
+pub fn check_config(config: &ConfigArgs) -> Result<(), Terminator> {
+    let config_path = config.path();
+    let source = config.read_config()?;
+
+    // A configuration that cannot be parsed can't be checked any further
+    let parsed = SourceMap::parse(&source)
+        .and_then(|source_map| Ok((source_map, toml::from_str::<TaskConfig>(&source)?)));
+    let (source_map, task_config) = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            let location = err.line_col().map(|(line, col)| SourceLocation {line: line + 1, col: col + 1});
+            print_problem(config_path, location, &err.to_string());
+            return Err("unable to parse configuration file".into());
+        },
+    };
+
+    let base_dir = config.base_directory()?;
+    let problems = check::check_config(&task_config, &base_dir);
+    for problem in &problems {
+        print_problem(config_path, source_map.locate(&problem.key), &problem.message);
+    }
+
+    match problems.len() {
+        0 => {
+            println!("{}: no problems found", config_path.display());
+            Ok(())
+        },
+        1 => Err("found 1 problem in configuration file".into()),
+        count => Err(format!("found {} problems in configuration file", count).into()),
+    }
+}
+
+fn print_problem(config_path: &Path, location: Option<SourceLocation>, message: &str) {
+    eprintln!("error: {}", message);
+    match location {
+        Some(location) => eprintln!("  --> {}:{}", config_path.display(), location),
+        None => eprintln!("  --> {}", config_path.display()),
+    }
+}
@@ -0,0 +1,82 @@
+use terminator::Terminator;
+use spritec::{
+    math::Vec3,
+    query3d::{File, QueryBackend, ModelInfo, SceneInfo, NodeInfo, AnimationInfo, CameraInfo, LightInfo, MaterialInfo},
+};
+
+use crate::args::InspectArgs;
+
+/// Prints a summary of everything in the given model file
+pub fn inspect_model(args: &InspectArgs) -> Result<(), Terminator> {
+    let InspectArgs {model_path, json} = args;
+
+    let info = File::open(model_path)?.inspect();
+    if *json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        print_info(&info);
+    }
+
+    Ok(())
+}
+
+fn print_info(info: &ModelInfo) {
+    let ModelInfo {scenes, animations, cameras, lights, materials} = info;
+
+    println!("Scenes:");
+    for scene in scenes {
+        let SceneInfo {name, default, nodes, bounds} = scene;
+        let default = if *default { " (default)" } else { "" };
+        match bounds {
+            Some(bounds) => println!("  {}{}, bounds {} to {}",
+                display_name(name), default, display_vec(bounds.min), display_vec(bounds.max)),
+            None => println!("  {}{}, no geometry", display_name(name), default),
+        }
+
+        for node in nodes {
+            print_node(node, 2);
+        }
+    }
+
+    println!("Animations:");
+    for AnimationInfo {name, duration} in animations {
+        println!("  {}, {} ms", display_name(name), duration.to_msec());
+    }
+
+    println!("Cameras:");
+    for CameraInfo {name, node, projection} in cameras {
+        println!("  {} (node {}), {:?}", display_name(name), display_name(node), projection);
+    }
+
+    println!("Lights:");
+    for LightInfo {name, node, kind} in lights {
+        println!("  {} (node {}), {:?}", display_name(name), display_name(node), kind);
+    }
+
+    println!("Materials:");
+    for MaterialInfo {name, textured} in materials {
+        let textured = if *textured { ", textured" } else { "" };
+        println!("  {}{}", display_name(name), textured);
+    }
+}
+
+fn print_node(node: &NodeInfo, depth: usize) {
+    let NodeInfo {name, kind, children} = node;
+    println!("{:indent$}{} [{:?}]", "", display_name(name), kind, indent = depth * 2);
+
+    for child in children {
+        print_node(child, depth + 1);
+    }
+}
+
+/// Names that are missing or empty are both shown as unnamed
+fn display_name(name: &Option<String>) -> &str {
+    match name.as_deref() {
+        None | Some("") => "<unnamed>",
+        Some(name) => name,
+    }
+}
+
+fn display_vec(Vec3 {x, y, z}: Vec3) -> String {
+    format!("({:.3}, {:.3}, {:.3})", x, y, z)
+}
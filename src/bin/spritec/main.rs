@@ -13,54 +13,91 @@
 #![deny(bare_trait_objects)] // Prefer Box<dyn Trait> over Box<Trait>
 
 mod args;
+mod check;
+mod inspect;
+mod report;
 
 use std::path::Path;
+use std::num::NonZeroUsize;
 
 use terminator::Terminator;
 use structopt::StructOpt;
 use spritec::{
-    tasks::{self, Task, WeakFileCache},
-    query3d::FileError,
-    config::{TaskConfig, Spritesheet, Pose},
-    renderer::ThreadRenderContext,
+    tasks::{self, TaskSpec, TaskOutcome},
+    config::TaskConfig,
 };
 
-use crate::args::AppArgs;
+use crate::args::{AppArgs, ConfigArgs, Command};
+use crate::report::BatchReport;
 
 fn main() -> Result<(), Terminator> {
-    let args = AppArgs::from_args();
-    let TaskConfig {spritesheets, poses} = args.load_config()?;
-    let base_dir = args.base_directory()?;
-
-    // HACK: File cache should be created *within* create_tasks so it can be dropped before
-    //   tasks run. See HACK notes in `file_cache.rs`
-    let mut file_cache = WeakFileCache::default();
-    let tasks = create_tasks(&mut file_cache, spritesheets, poses, &base_dir)?;
-
-    let mut ctx = ThreadRenderContext::new()?;
-    // This loop should not be parallelised. Rendering is done in parallel on the
-    // GPU and is orchestrated by the renderer. Trying to do that here with threads
-    // will only create contention.
-    for task in tasks {
-        task.execute(&mut ctx)?;
-    }
+    let AppArgs {config, keep_going, report, jobs, command} = AppArgs::from_args();
 
-    Ok(())
+    match command {
+        Some(Command::Check(config)) => check::check_config(&config),
+        Some(Command::Inspect(args)) => inspect::inspect_model(&args),
+        None => run_tasks(&config, keep_going, report.as_deref(), jobs),
+    }
 }
 
-fn create_tasks(
-    file_cache: &mut WeakFileCache,
-    spritesheets: Vec<Spritesheet>,
-    poses: Vec<Pose>,
-    base_dir: &Path,
-) -> Result<Vec<Task>, FileError> {
-    let mut tasks = Vec::new();
-    for sheet in spritesheets {
-        tasks.push(tasks::generate_spritesheet_task(sheet, base_dir, file_cache)?);
+fn run_tasks(
+    config: &ConfigArgs,
+    keep_going: bool,
+    report_path: Option<&Path>,
+    jobs: NonZeroUsize,
+) -> Result<(), Terminator> {
+    let TaskConfig {spritesheets, poses} = config.load_config()?;
+    let base_dir = config.base_directory()?;
+
+    let mut names = Vec::new();
+    let mut specs = Vec::new();
+    for (i, sheet) in spritesheets.into_iter().enumerate() {
+        names.push(format!("spritesheets[{}]", i));
+        specs.push(TaskSpec::Spritesheet(sheet));
     }
-    for pose in poses {
-        tasks.push(tasks::generate_pose_task(pose, base_dir, file_cache)?);
+    for (i, pose) in poses.into_iter().enumerate() {
+        names.push(format!("poses[{}]", i));
+        specs.push(TaskSpec::Pose(pose));
     }
+    let output_paths: Vec<_> = specs.iter().map(|spec| spec.output_path(&base_dir)).collect();
+
+    let mut outcomes: Vec<Option<TaskOutcome>> = specs.iter().map(|_| None).collect();
+    tasks::execute_tasks(specs, &base_dir, jobs, |outcome| {
+        let failed = outcome.result.is_err();
+        let index = outcome.index;
+        outcomes[index] = Some(outcome);
+
+        keep_going || !failed
+    })?;
+
+    let mut report = BatchReport::default();
+    let mut first_error = None;
+    for ((name, output_path), outcome) in names.into_iter().zip(output_paths).zip(outcomes) {
+        match outcome {
+            Some(TaskOutcome {index: _, duration, result: Ok(())}) => {
+                report.succeeded(name, output_path, duration);
+            },
+
+            Some(TaskOutcome {index: _, duration, result: Err(err)}) => {
+                report.failed(name, output_path, duration, &err);
+                first_error.get_or_insert(err);
+            },
 
-    Ok(tasks)
+            // Tasks that never ran because an earlier task failed
+            None => report.skipped(name, output_path),
+        }
+    }
+
+    if keep_going {
+        report.print_summary();
+    }
+    if let Some(report_path) = report_path {
+        report.write_json(report_path)?;
+    }
+
+    match first_error {
+        None => Ok(()),
+        Some(err) if !keep_going => Err(err.into()),
+        Some(_) => Err(format!("{} of {} tasks failed", report.failures(), report.tasks.len()).into()),
+    }
 }
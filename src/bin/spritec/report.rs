@@ -0,0 +1,135 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::error::Error;
+
+use serde::Serialize;
+
+/// The outcome of running a single task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskStatus {
+    Succeeded,
+    Failed,
+    /// The task was not run because an earlier task failed
+    Skipped,
+}
+
+impl TaskStatus {
+    fn as_str(self) -> &'static str {
+        use TaskStatus::*;
+        match self {
+            Succeeded => "ok",
+            Failed => "FAILED",
+            Skipped => "skipped",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskReport {
+    /// The key of the task in the configuration file (e.g. `spritesheets[0]`)
+    pub task: String,
+    /// The absolute path that the task outputs to
+    pub output_path: PathBuf,
+    pub status: TaskStatus,
+    /// The time taken to create and run the task, in seconds
+    #[serde(rename = "duration_secs", serialize_with = "serialize_secs")]
+    pub duration: Duration,
+    /// The error produced by the task, if it failed
+    pub error: Option<String>,
+}
+
+/// A report of every task run from a configuration file
+#[derive(Debug, Default, Serialize)]
+pub struct BatchReport {
+    pub tasks: Vec<TaskReport>,
+}
+
+impl BatchReport {
+    pub fn succeeded(&mut self, task: String, output_path: PathBuf, duration: Duration) {
+        self.tasks.push(TaskReport {task, output_path, status: TaskStatus::Succeeded, duration, error: None});
+    }
+
+    pub fn failed(&mut self, task: String, output_path: PathBuf, duration: Duration, error: &dyn Error) {
+        self.tasks.push(TaskReport {
+            task,
+            output_path,
+            status: TaskStatus::Failed,
+            duration,
+            error: Some(error.to_string()),
+        });
+    }
+
+    pub fn skipped(&mut self, task: String, output_path: PathBuf) {
+        self.tasks.push(TaskReport {
+            task,
+            output_path,
+            status: TaskStatus::Skipped,
+            duration: Duration::default(),
+            error: None,
+        });
+    }
+
+    /// Returns the number of tasks that failed
+    pub fn failures(&self) -> usize {
+        self.count(TaskStatus::Failed)
+    }
+
+    /// Prints a table with a row for each task
+    pub fn print_summary(&self) {
+        const HEADERS: [&str; 5] = ["Task", "Output", "Status", "Duration", "Error"];
+
+        let rows: Vec<[String; 5]> = self.tasks.iter().map(|report| {
+            let TaskReport {task, output_path, status, duration, error} = report;
+            [
+                task.clone(),
+                output_path.display().to_string(),
+                status.as_str().to_string(),
+                format!("{:.2}s", duration.as_secs_f64()),
+                error.clone().unwrap_or_default(),
+            ]
+        }).collect();
+
+        let mut widths = [0; 5];
+        for row in std::iter::once(&HEADERS.map(String::from)).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let print_row = |row: &[String; 5]| {
+            let cells: Vec<_> = row.iter().zip(&widths)
+                .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+                .collect();
+            println!("{}", cells.join("  ").trim_end());
+        };
+
+        print_row(&HEADERS.map(String::from));
+        for row in &rows {
+            print_row(row);
+        }
+
+        println!();
+        println!("{} tasks: {} succeeded, {} failed, {} skipped",
+            self.tasks.len(),
+            self.count(TaskStatus::Succeeded),
+            self.count(TaskStatus::Failed),
+            self.count(TaskStatus::Skipped));
+    }
+
+    /// Writes the report to the given path as JSON
+    pub fn write_json(&self, path: &Path) -> Result<(), io::Error> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)
+    }
+
+    fn count(&self, status: TaskStatus) -> usize {
+        self.tasks.iter().filter(|task| task.status == status).count()
+    }
+}
+
+fn serialize_secs<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
@@ -0,0 +1,1010 @@
+//! Validation of configuration files without rendering anything
+
+mod source_map;
+
+pub use source_map::*;
+
+use std::path::Path;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use crate::math::Milliseconds;
+use crate::config::{self, TaskConfig, UnresolvedPath};
+use crate::tasks::{self, WeakFileCache};
+use crate::query3d::{
+    File,
+    FileError,
+    QueryError,
+    QueryBackend,
+    CameraQuery,
+    LightQuery,
+    GeometryQuery,
+    GeometryFilter,
+    AnimationQuery,
+    AnimationPosition,
+    NodeQuery,
+};
+
+/// A problem found while checking a configuration file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigProblem {
+    /// The key of the value that caused the problem (e.g. `spritesheets[0].animations[1].camera`)
+    ///
+    /// See `SourceMap::locate` to find where this key is in the configuration file.
+    pub key: String,
+    /// A description of the problem
+    pub message: String,
+}
+
+/// Checks that every task in the configuration can be run, without rendering anything
+///
+/// All paths are resolved relative to the given base directory and each model is opened to make
+/// sure that any animations, cameras and scenes referred to by name actually exist. Every problem
+/// found is returned, not just the first one.
+pub fn check_config(config: &TaskConfig, base_dir: &Path) -> Vec<ConfigProblem> {
+    let TaskConfig {spritesheets, poses} = config;
+
+    let mut checker = Checker {
+        base_dir,
+        file_cache: WeakFileCache::default(),
+        problems: Vec::new(),
+    };
+
+    for (i, sheet) in spritesheets.iter().enumerate() {
+        checker.check_spritesheet(&format!("spritesheets[{}]", i), sheet);
+    }
+    for (i, pose) in poses.iter().enumerate() {
+        checker.check_pose(&format!("poses[{}]", i), pose);
+    }
+
+    checker.problems
+}
+
+struct Checker<'a> {
+    base_dir: &'a Path,
+    file_cache: WeakFileCache,
+    problems: Vec<ConfigProblem>,
+}
+
+/// The attachment points and hitboxes of an animation or pose, along with its key
+struct Markers<'a> {
+    key: &'a str,
+    attachment_points: &'a [config::AttachmentPoint],
+    hitboxes: &'a [config::Hitbox],
+}
+
+impl<'a> Checker<'a> {
+    fn problem(&mut self, key: String, message: impl Into<String>) {
+        // The same problem can be found more than once when an animation is checked for every
+        // scene in a file
+        let problem = ConfigProblem {key, message: message.into()};
+        if !self.problems.contains(&problem) {
+            self.problems.push(problem);
+        }
+    }
+
+    fn check_spritesheet(&mut self, key: &str, sheet: &config::Spritesheet) {
+        let config::Spritesheet {
+            path,
+            metadata_path,
+            cell_width: _,
+            cell_height: _,
+            animations,
+            scale: _,
+            background: _,
+            color_transfer,
+            alpha,
+        } = sheet;
+
+        self.check_color_transfer(&format!("{}.color_transfer", key), color_transfer);
+        self.check_alpha(&format!("{}.alpha", key), alpha);
+
+        self.check_output_path(&format!("{}.path", key), path);
+        if let Some(metadata_path) = metadata_path {
+            self.check_output_path(&format!("{}.metadata_path", key), metadata_path);
+        }
+
+        if animations.is_empty() {
+            self.problem(format!("{}.animations", key), "Spritesheet must have at least one animation");
+        }
+
+        let mut anim_names = HashSet::new();
+        for (i, anim) in animations.iter().enumerate() {
+            let anim_key = format!("{}.animations[{}]", key, i);
+            // Animations without a name use their index in the metadata file
+            let (name_key, name) = match &anim.name {
+                Some(name) => (format!("{}.name", anim_key), name.clone()),
+                None => (anim_key.clone(), i.to_string()),
+            };
+            if !anim_names.insert(name.clone()) {
+                self.problem(name_key, format!("Multiple animations are named `{}`", name));
+            }
+
+            self.check_animation(&anim_key, anim);
+
+            use config::AnimationFrames::*;
+            let root_motion = match &anim.frames {
+                GltfFrames {root_motion, ..} | GltfFrameTimes {root_motion, ..} => root_motion.as_ref(),
+                Models(_) => None,
+            };
+            if metadata_path.is_none() && root_motion.map(|root_motion| root_motion.export_offsets).unwrap_or(false) {
+                self.problem(format!("{}.frames.root_motion.export_offsets", anim_key),
+                    "Offsets are written to the metadata file, so the spritesheet must have a `metadata_path`");
+            }
+            if metadata_path.is_none() && matches!(anim.pivot, Some(config::Pivot {align_to: None, ..})) {
+                self.problem(format!("{}.pivot", anim_key),
+                    "Pivots are written to the metadata file, so the spritesheet must have a `metadata_path` unless `align_to` is set");
+            }
+        }
+    }
+
+    fn check_animation(&mut self, key: &str, anim: &config::Animation) {
+        let config::Animation {
+            name: _,
+            frames,
+            gif_path,
+            frame_width: _,
+            frame_height: _,
+            camera,
+            lights,
+            camera_follow,
+            outline,
+            extra_models,
+            shadows,
+            rim_light,
+            specular,
+            materials,
+            max_lights: _,
+            pivot,
+            attachment_points,
+            hitboxes,
+        } = anim;
+
+        if let Some(gif_path) = gif_path {
+            self.check_output_path(&format!("{}.gif_path", key), gif_path);
+        }
+        self.check_outline(&format!("{}.outline", key), outline);
+        if let Some(shadows) = shadows {
+            self.check_shadows(&format!("{}.shadows", key), shadows);
+        }
+        self.check_highlights(key, rim_light.as_ref(), specular.as_ref(), materials);
+        let extras_key = format!("{}.extra_models", key);
+        let animated = !matches!(frames, config::AnimationFrames::Models(_));
+        self.check_extra_models(&extras_key, extra_models, animated);
+
+        let frames_key = format!("{}.frames", key);
+        let camera_key = format!("{}.camera", key);
+        let lights_key = format!("{}.lights", key);
+        let follow_key = format!("{}.camera_follow", key);
+        let pivot_key = format!("{}.pivot", key);
+        let markers = Markers {key, attachment_points, hitboxes};
+        self.check_marker_names(&markers);
+        use config::AnimationFrames::*;
+        match frames {
+            GltfFrames {
+                gltf,
+                scene,
+                all_scenes,
+                animation,
+                start_time,
+                end_time,
+                steps,
+                fps,
+                easing: _,
+                loop_safe,
+                loop_offset,
+                playback,
+                holds,
+                layers,
+                root_motion,
+            } => {
+                let file = match self.open_gltf(&format!("{}.gltf", frames_key), gltf) {
+                    Some(file) => file,
+                    None => return,
+                };
+
+                if let Some(end_time) = end_time {
+                    if end_time < start_time {
+                        self.problem(format!("{}.end_time", frames_key),
+                            "End time must not be before the start time");
+                    }
+                }
+                if !loop_offset.is_finite() {
+                    self.problem(format!("{}.loop_offset", frames_key), "Loop offset must be a finite number");
+                }
+                let steps = match (steps, fps) {
+                    (Some(steps), None) => Some(*steps),
+                    (None, Some(fps)) if !(fps.is_finite() && *fps > 0.0) => {
+                        self.problem(format!("{}.fps", frames_key), "Frame rate must be greater than zero");
+                        None
+                    },
+                    (None, Some(fps)) => {
+                        // If the animation can't be found, that will be reported when the
+                        // geometry is checked below
+                        let end_time = end_time.map(Ok).unwrap_or_else(|| {
+                            let file = file.lock().expect("bug: file lock was poisoned");
+                            file.query_animation_end_time(animation.as_deref())
+                        });
+                        let steps = end_time.ok()
+                            .map(|end_time| tasks::fps_steps(*start_time, end_time, *fps, *loop_safe));
+                        match steps {
+                            Some(None) => {
+                                self.problem(format!("{}.fps", frames_key), format!("Frame rate must sample \
+                                    at most {} frames", tasks::MAX_FPS_STEPS));
+                                None
+                            },
+                            steps => steps.flatten().map(|(steps, _)| steps),
+                        }
+                    },
+                    (Some(_), Some(_)) => {
+                        self.problem(frames_key.clone(), "Only one of `steps` or `fps` may be provided");
+                        None
+                    },
+                    (None, None) => {
+                        self.problem(frames_key.clone(), "One of `steps` or `fps` must be provided");
+                        None
+                    },
+                };
+                if let Some(steps) = steps {
+                    self.check_holds(&format!("{}.holds", frames_key), holds, playback.len(steps.get()));
+                }
+                self.check_layers(&format!("{}.layers", frames_key), layers);
+                self.check_root_motion(&format!("{}.root_motion", frames_key), root_motion);
+
+                let position = AnimationPosition::Time(*start_time);
+                for scene in self.check_scenes(&frames_key, &file, scene, *all_scenes) {
+                    let query = GeometryQuery {
+                        models: GeometryFilter::Scene {name: scene.clone()},
+                        animation: Some(AnimationQuery {
+                            name: animation.clone(),
+                            layers: tasks::config_to_layers(layers, &position),
+                            position: position.clone(),
+                            root_motion: tasks::config_to_root_motion(root_motion.clone()).0,
+                        }),
+                    };
+                    self.check_geometry(&frames_key, &file, &query);
+                    self.check_camera(&camera_key, &file, camera, scene.as_ref());
+                    self.check_lights(&lights_key, &file, lights.as_ref(), scene.as_ref());
+                    self.check_camera_follow(&follow_key, &file, camera_follow, scene.as_ref());
+                    self.check_attachments(&extras_key, extra_models, &file, scene.as_ref());
+                    self.check_pivot(&pivot_key, pivot.as_ref(), &file, scene.as_ref());
+                    self.check_markers(&markers, &file, scene.as_ref());
+                }
+            },
+
+            GltfFrameTimes {gltf, scene, all_scenes, animation, times, playback, holds, layers, root_motion} => {
+                let file = match self.open_gltf(&format!("{}.gltf", frames_key), gltf) {
+                    Some(file) => file,
+                    None => return,
+                };
+
+                if times.is_empty() {
+                    self.problem(format!("{}.times", frames_key), "Animation must have at least one time");
+                }
+                if let Some(index) = tasks::backwards_time(times) {
+                    self.problem(format!("{}.times[{}]", frames_key, index),
+                        "Times must be in order, but this time is before the time before it");
+                }
+                self.check_holds(&format!("{}.holds", frames_key), holds, playback.len(times.len() as u32));
+                self.check_layers(&format!("{}.layers", frames_key), layers);
+                self.check_root_motion(&format!("{}.root_motion", frames_key), root_motion);
+
+                let position = AnimationPosition::Time(times.first().copied().unwrap_or_default());
+                for scene in self.check_scenes(&frames_key, &file, scene, *all_scenes) {
+                    let query = GeometryQuery {
+                        models: GeometryFilter::Scene {name: scene.clone()},
+                        animation: Some(AnimationQuery {
+                            name: animation.clone(),
+                            layers: tasks::config_to_layers(layers, &position),
+                            position: position.clone(),
+                            root_motion: tasks::config_to_root_motion(root_motion.clone()).0,
+                        }),
+                    };
+                    self.check_geometry(&frames_key, &file, &query);
+                    self.check_camera(&camera_key, &file, camera, scene.as_ref());
+                    self.check_lights(&lights_key, &file, lights.as_ref(), scene.as_ref());
+                    self.check_camera_follow(&follow_key, &file, camera_follow, scene.as_ref());
+                    self.check_attachments(&extras_key, extra_models, &file, scene.as_ref());
+                    self.check_pivot(&pivot_key, pivot.as_ref(), &file, scene.as_ref());
+                    self.check_markers(&markers, &file, scene.as_ref());
+                }
+            },
+
+            Models(models) => {
+                if camera_follow.is_some() {
+                    self.problem(follow_key, "Camera follow can only be used with glTF animation frames");
+                }
+
+                if models.is_empty() {
+                    self.problem(frames_key.clone(), "Animation must have at least one model");
+                }
+
+                for (i, model) in models.iter().enumerate() {
+                    let model_key = format!("{}[{}]", frames_key, i);
+                    let file = match self.open(&model_key, model) {
+                        Some(file) => file,
+                        None => continue,
+                    };
+
+                    let query = GeometryQuery {
+                        models: GeometryFilter::all_in_default_scene(),
+                        animation: None,
+                    };
+                    self.check_geometry(&model_key, &file, &query);
+                    self.check_camera(&camera_key, &file, camera, None);
+                    self.check_lights(&lights_key, &file, lights.as_ref(), None);
+                    self.check_attachments(&extras_key, extra_models, &file, None);
+                    self.check_pivot(&pivot_key, pivot.as_ref(), &file, None);
+                    self.check_markers(&markers, &file, None);
+                }
+            },
+        }
+    }
+
+    fn check_pose(&mut self, key: &str, pose: &config::Pose) {
+        let config::Pose {
+            model,
+            path,
+            metadata_path,
+            width: _,
+            height: _,
+            camera,
+            lights,
+            scale: _,
+            background: _,
+            color_transfer,
+            alpha,
+            outline,
+            extra_models,
+            shadows,
+            rim_light,
+            specular,
+            materials,
+            max_lights: _,
+            pivot,
+            attachment_points,
+            hitboxes,
+        } = pose;
+
+        self.check_output_path(&format!("{}.path", key), path);
+        if let Some(metadata_path) = metadata_path {
+            self.check_output_path(&format!("{}.metadata_path", key), metadata_path);
+        }
+        if metadata_path.is_none() && matches!(pivot, Some(config::Pivot {align_to: None, ..})) {
+            self.problem(format!("{}.pivot", key),
+                "Pivots are written to the metadata file, so the pose must have a `metadata_path` unless `align_to` is set");
+        }
+        self.check_color_transfer(&format!("{}.color_transfer", key), color_transfer);
+        self.check_alpha(&format!("{}.alpha", key), alpha);
+        self.check_outline(&format!("{}.outline", key), outline);
+        if let Some(shadows) = shadows {
+            self.check_shadows(&format!("{}.shadows", key), shadows);
+        }
+        self.check_highlights(key, rim_light.as_ref(), specular.as_ref(), materials);
+        let extras_key = format!("{}.extra_models", key);
+        let animated = matches!(model, config::PoseModel::GltfFrame {..});
+        self.check_extra_models(&extras_key, extra_models, animated);
+
+        let model_key = format!("{}.model", key);
+        let camera_key = format!("{}.camera", key);
+        let lights_key = format!("{}.lights", key);
+        let pivot_key = format!("{}.pivot", key);
+        let markers = Markers {key, attachment_points, hitboxes};
+        self.check_marker_names(&markers);
+        match model {
+            config::PoseModel::GltfFrame {gltf, scene, animation, time, layers} => {
+                let file = match self.open_gltf(&format!("{}.gltf", model_key), gltf) {
+                    Some(file) => file,
+                    None => return,
+                };
+                self.check_layers(&format!("{}.layers", model_key), layers);
+
+                let position = AnimationPosition::Time(*time);
+                let query = GeometryQuery {
+                    models: GeometryFilter::Scene {name: scene.clone()},
+                    animation: Some(AnimationQuery {
+                        name: animation.clone(),
+                        layers: tasks::config_to_layers(layers, &position),
+                        position,
+                        root_motion: None,
+                    }),
+                };
+                self.check_geometry(&model_key, &file, &query);
+                self.check_camera(&camera_key, &file, camera, scene.as_ref());
+                self.check_lights(&lights_key, &file, lights.as_ref(), scene.as_ref());
+                self.check_attachments(&extras_key, extra_models, &file, scene.as_ref());
+                self.check_pivot(&pivot_key, pivot.as_ref(), &file, scene.as_ref());
+                self.check_markers(&markers, &file, scene.as_ref());
+            },
+
+            config::PoseModel::Model(path) => {
+                let file = match self.open(&model_key, path) {
+                    Some(file) => file,
+                    None => return,
+                };
+
+                let query = GeometryQuery {
+                    models: GeometryFilter::all_in_default_scene(),
+                    animation: None,
+                };
+                self.check_geometry(&model_key, &file, &query);
+                self.check_camera(&camera_key, &file, camera, None);
+                self.check_lights(&lights_key, &file, lights.as_ref(), None);
+                self.check_attachments(&extras_key, extra_models, &file, None);
+                self.check_pivot(&pivot_key, pivot.as_ref(), &file, None);
+                self.check_markers(&markers, &file, None);
+            },
+        }
+    }
+
+    /// Checks each extra model, where `animated` is true if the extra models will be given an
+    /// animation position to sample their animations at
+    fn check_extra_models(&mut self, key: &str, models: &[config::ExtraModel], animated: bool) {
+        for (i, extra_model) in models.iter().enumerate() {
+            let key = format!("{}[{}]", key, i);
+            let config::ExtraModel {model, scene, animation, attach_to: _, offset: _} = extra_model;
+
+            if animation.is_some() && !animated {
+                self.problem(format!("{}.animation", key),
+                    "Extra model animations can only be used with glTF animation frames and poses");
+            }
+
+            let file = match self.open(&format!("{}.model", key), model) {
+                Some(file) => file,
+                None => continue,
+            };
+
+            let query = GeometryQuery {
+                models: GeometryFilter::Scene {name: scene.clone()},
+                animation: animation.as_ref().filter(|_| animated).map(|name| AnimationQuery {
+                    name: Some(name.clone()),
+                    position: AnimationPosition::Time(Milliseconds::from_msec(0.0)),
+                    layers: Vec::new(),
+                    root_motion: None,
+                }),
+            };
+            self.check_geometry(&key, &file, &query);
+        }
+    }
+
+    /// Checks that every node that an extra model is attached to exists in the scene of the given
+    /// main model
+    fn check_attachments(
+        &mut self,
+        key: &str,
+        models: &[config::ExtraModel],
+        file: &Arc<Mutex<File>>,
+        scene: Option<&String>,
+    ) {
+        for (i, extra_model) in models.iter().enumerate() {
+            let node = match &extra_model.attach_to {
+                Some(node) => node,
+                None => continue,
+            };
+
+            let query = NodeQuery {name: node.clone(), scene: scene.cloned(), animation: None};
+            let result = {
+                let file = file.lock().expect("bug: file lock was poisoned");
+                file.query_node_transform(&query)
+            };
+
+            match result {
+                // An unknown scene being rendered is reported when the geometry is checked
+                Err(QueryError::UnknownScene {..}) => {},
+                Err(err) => self.problem(format!("{}[{}].attach_to", key, i), err.to_string()),
+                Ok(_) => {},
+            }
+        }
+    }
+
+    /// Checks that the node the pivot moves along with exists in the scene of the given model
+    fn check_pivot(
+        &mut self,
+        key: &str,
+        pivot: Option<&config::Pivot>,
+        file: &Arc<Mutex<File>>,
+        scene: Option<&String>,
+    ) {
+        let node = match pivot.and_then(|pivot| pivot.node.as_ref()) {
+            Some(node) => node,
+            None => return,
+        };
+
+        let query = NodeQuery {name: node.clone(), scene: scene.cloned(), animation: None};
+        let result = {
+            let file = file.lock().expect("bug: file lock was poisoned");
+            file.query_node_transform(&query)
+        };
+
+        match result {
+            // An unknown scene being rendered is reported when the geometry is checked
+            Err(QueryError::UnknownScene {..}) => {},
+            Err(err) => self.problem(format!("{}.node", key), err.to_string()),
+            Ok(_) => {},
+        }
+    }
+
+    /// Checks that the attachment points and hitboxes of an animation or pose have unique names
+    fn check_marker_names(&mut self, markers: &Markers) {
+        let &Markers {key, attachment_points, hitboxes} = markers;
+
+        let mut names = HashSet::new();
+        for (i, point) in attachment_points.iter().enumerate() {
+            if !names.insert(&point.name) {
+                self.problem(format!("{}.attachment_points[{}].name", key, i),
+                    format!("Multiple attachment points are named `{}`", point.name));
+            }
+        }
+
+        let mut names = HashSet::new();
+        for (i, hitbox) in hitboxes.iter().enumerate() {
+            if !names.insert(&hitbox.name) {
+                self.problem(format!("{}.hitboxes[{}].name", key, i),
+                    format!("Multiple hitboxes are named `{}`", hitbox.name));
+            }
+        }
+    }
+
+    /// Checks that the node of every attachment point exists and that the node of every hitbox
+    /// has a mesh, in the scene of the given model
+    fn check_markers(&mut self, markers: &Markers, file: &Arc<Mutex<File>>, scene: Option<&String>) {
+        let &Markers {key, attachment_points, hitboxes} = markers;
+
+        let node_query = |node: &String| NodeQuery {name: node.clone(), scene: scene.cloned(), animation: None};
+        let results: Vec<_> = {
+            let file = file.lock().expect("bug: file lock was poisoned");
+            let points = attachment_points.iter().enumerate().map(|(i, point)| {
+                let result = file.query_node_transform(&node_query(&point.node)).map(|_| ());
+                (format!("{}.attachment_points[{}].node", key, i), result)
+            });
+            let hitboxes = hitboxes.iter().enumerate().map(|(i, hitbox)| {
+                let result = file.query_mesh_positions(&node_query(&hitbox.node)).map(|_| ());
+                (format!("{}.hitboxes[{}].node", key, i), result)
+            });
+            points.chain(hitboxes).collect()
+        };
+
+        for (node_key, result) in results {
+            match result {
+                // An unknown scene being rendered is reported when the geometry is checked
+                Err(QueryError::UnknownScene {..}) => {},
+                Err(err) => self.problem(node_key, err.to_string()),
+                Ok(()) => {},
+            }
+        }
+    }
+
+    fn check_output_path(&mut self, key: &str, path: &UnresolvedPath) {
+        let path = path.resolve(self.base_dir);
+        match path.parent() {
+            Some(dir) if !dir.is_dir() => {
+                self.problem(key.to_string(), format!("Output directory `{}` does not exist", dir.display()));
+            },
+            _ => {},
+        }
+    }
+
+    fn check_color_transfer(&mut self, key: &str, color_transfer: &config::ColorTransfer) {
+        if let &config::ColorTransfer::Gamma(gamma) = color_transfer {
+            if !(gamma > 0.0 && gamma.is_finite()) {
+                self.problem(format!("{}.Gamma", key), "Gamma must be greater than zero");
+            }
+        }
+    }
+
+    fn check_alpha(&mut self, key: &str, alpha: &config::AlphaOutput) {
+        let config::AlphaOutput {mode: _, threshold, remove_fringe: _} = alpha;
+
+        if !(0.0..=1.0).contains(threshold) {
+            self.problem(format!("{}.threshold", key), "Alpha threshold must be between 0.0 and 1.0");
+        }
+    }
+
+    fn check_outline(&mut self, key: &str, outline: &config::Outline) {
+        if outline.thickness < 0.0 {
+            self.problem(format!("{}.thickness", key), "Outline thickness must not be negative");
+        }
+    }
+
+    fn check_highlights(
+        &mut self,
+        key: &str,
+        rim_light: Option<&config::RimLight>,
+        specular: Option<&config::Specular>,
+        materials: &[config::MaterialHighlights],
+    ) {
+        if let Some(rim_light) = rim_light {
+            self.check_rim_light(&format!("{}.rim_light", key), rim_light);
+        }
+        if let Some(specular) = specular {
+            self.check_specular(&format!("{}.specular", key), specular);
+        }
+
+        for (i, material) in materials.iter().enumerate() {
+            let material_key = format!("{}.materials[{}]", key, i);
+            let config::MaterialHighlights {name, rim_light, specular} = material;
+
+            if materials[..i].iter().any(|other| other.name == *name) {
+                self.problem(format!("{}.name", material_key),
+                    format!("Material `{}` is configured more than once", name));
+            }
+            if let Some(rim_light) = rim_light {
+                self.check_rim_light(&format!("{}.rim_light", material_key), rim_light);
+            }
+            if let Some(specular) = specular {
+                self.check_specular(&format!("{}.specular", material_key), specular);
+            }
+        }
+    }
+
+    fn check_rim_light(&mut self, key: &str, rim_light: &config::RimLight) {
+        let config::RimLight {color: _, width, threshold} = rim_light;
+
+        if !(0.0..=1.0).contains(width) {
+            self.problem(format!("{}.width", key), "Rim light width must be between 0.0 and 1.0");
+        }
+        if !(0.0..=1.0).contains(threshold) {
+            self.problem(format!("{}.threshold", key), "Rim light threshold must be between 0.0 and 1.0");
+        }
+    }
+
+    fn check_specular(&mut self, key: &str, specular: &config::Specular) {
+        let config::Specular {color: _, shininess, threshold} = specular;
+
+        if let Some(shininess) = shininess {
+            if !(*shininess > 0.0 && shininess.is_finite()) {
+                self.problem(format!("{}.shininess", key), "Shininess must be greater than zero");
+            }
+        }
+        if !(0.0..=1.0).contains(threshold) {
+            self.problem(format!("{}.threshold", key), "Specular threshold must be between 0.0 and 1.0");
+        }
+    }
+
+    fn check_shadows(&mut self, key: &str, shadows: &config::Shadows) {
+        let config::Shadows {tint: _, extent, map_size: _, drop_shadow} = shadows;
+
+        if !(*extent > 0.0 && extent.is_finite()) {
+            self.problem(format!("{}.extent", key), "Shadow extent must be greater than zero");
+        }
+
+        if let Some(drop_shadow) = drop_shadow {
+            let config::DropShadow {style, ground_height, color: _, radius} = drop_shadow;
+
+            if !ground_height.is_finite() {
+                self.problem(format!("{}.drop_shadow.ground_height", key), "Ground height must be a finite number");
+            }
+            if *style == config::DropShadowStyle::Blob && !(*radius > 0.0 && radius.is_finite()) {
+                self.problem(format!("{}.drop_shadow.radius", key), "Blob radius must be greater than zero");
+            }
+        }
+    }
+
+    fn check_holds(&mut self, key: &str, holds: &[config::Hold], frames: u32) {
+        for (i, hold) in holds.iter().enumerate() {
+            if hold.frame >= frames {
+                self.problem(format!("{}[{}].frame", key, i),
+                    format!("Frame {} does not exist, the animation only has {} frames", hold.frame, frames));
+            }
+        }
+    }
+
+    fn check_layers(&mut self, key: &str, layers: &[config::AnimationLayer]) {
+        for (i, layer) in layers.iter().enumerate() {
+            if !(0.0..=1.0).contains(&layer.weight) {
+                self.problem(format!("{}[{}].weight", key, i), "Layer weight must be between 0.0 and 1.0");
+            }
+        }
+    }
+
+    fn check_root_motion(&mut self, key: &str, root_motion: &Option<config::RootMotion>) {
+        if let Some(config::RootMotion {node: _, axes, export_offsets: _}) = root_motion {
+            if axes.is_empty() {
+                self.problem(format!("{}.axes", key), "At least one axis must be locked");
+            }
+        }
+    }
+
+    fn check_geometry(&mut self, key: &str, file: &Arc<Mutex<File>>, query: &GeometryQuery) {
+        let result = {
+            let file = file.lock().expect("bug: file lock was poisoned");
+            file.check_geometry(query)
+        };
+
+        if let Err(err) = result {
+            // Point at the most specific value that could have caused the error
+            let layers = query.animation.as_ref().map(|anim| &anim.layers[..]).unwrap_or_default();
+            let key = match err {
+                QueryError::UnknownScene {..} => format!("{}.scene", key),
+                QueryError::UnknownAnimation {ref name} if !is_main_animation(query, name) => {
+                    match layers.iter().position(|layer| layer.name == *name) {
+                        Some(i) => format!("{}.layers[{}].animation", key, i),
+                        None => format!("{}.animation", key),
+                    }
+                },
+                QueryError::UnknownAnimation {..} |
+                QueryError::AmbiguousAnimation => format!("{}.animation", key),
+                QueryError::UnknownNode {ref name} if is_root_motion_node(query, name) => {
+                    format!("{}.root_motion.node", key)
+                },
+                QueryError::UnknownNode {ref name} => {
+                    match layers.iter().position(|layer| layer.mask.as_ref() == Some(name)) {
+                        Some(i) => format!("{}.layers[{}].mask", key, i),
+                        None => format!("{}.layers", key),
+                    }
+                },
+                _ => key.to_string(),
+            };
+            self.problem(key, err.to_string());
+        }
+    }
+
+    /// Returns the scenes that will each be rendered, where None is the default scene
+    fn check_scenes(
+        &mut self,
+        key: &str,
+        file: &Arc<Mutex<File>>,
+        scene: &Option<String>,
+        all_scenes: bool,
+    ) -> Vec<Option<String>> {
+        if !all_scenes {
+            return vec![scene.clone()];
+        }
+
+        if scene.is_some() {
+            self.problem(format!("{}.all_scenes", key), "Only one of `scene` or `all_scenes` may be provided");
+        }
+
+        let result = {
+            let file = file.lock().expect("bug: file lock was poisoned");
+            file.query_scene_names()
+        };
+
+        match result {
+            Ok(names) if names.is_empty() => {
+                self.problem(format!("{}.all_scenes", key), "Model file does not contain any scenes");
+                Vec::new()
+            },
+            Ok(names) => names.into_iter().map(Some).collect(),
+            Err(err) => {
+                self.problem(format!("{}.all_scenes", key), err.to_string());
+                Vec::new()
+            },
+        }
+    }
+
+    /// Checks the given camera, which is looked for in `default_scene` if it has no scene of its
+    /// own
+    fn check_camera(
+        &mut self,
+        key: &str,
+        file: &Arc<Mutex<File>>,
+        camera: &config::PresetCamera,
+        default_scene: Option<&String>,
+    ) {
+        let config::NamedCamera {name, scene} = match camera {
+            config::PresetCamera::Named(named) => named,
+            // Other cameras are not looked up in the model file
+            config::PresetCamera::Perspective(_) |
+            config::PresetCamera::Custom(_) => return,
+        };
+
+        let query = CameraQuery::Named {
+            name: name.clone(),
+            scene: scene.as_ref().or(default_scene).cloned(),
+            animation: None,
+        };
+        let result = {
+            let mut file = file.lock().expect("bug: file lock was poisoned");
+            file.query_camera(&query)
+        };
+
+        if let Err(err) = result {
+            let key = match err {
+                // An unknown scene being rendered is reported when the geometry is checked
+                QueryError::UnknownScene {..} if scene.is_none() => return,
+                QueryError::UnknownScene {..} => format!("{}.scene", key),
+                _ => format!("{}.name", key),
+            };
+            self.problem(key, err.to_string());
+        }
+    }
+
+    fn check_lights(
+        &mut self,
+        key: &str,
+        file: &Arc<Mutex<File>>,
+        lights: Option<&config::ModelLights>,
+        default_scene: Option<&String>,
+    ) {
+        let config::ModelLights {name, scene} = match lights {
+            Some(lights) => lights,
+            // The default light is not looked up in the model file
+            None => return,
+        };
+
+        let scene_name = scene.as_ref().or(default_scene).cloned();
+        let query = match name {
+            Some(name) => LightQuery::Named {name: name.clone(), scene: scene_name, animation: None},
+            None => LightQuery::Scene {name: scene_name, animation: None},
+        };
+        let result = {
+            let mut file = file.lock().expect("bug: file lock was poisoned");
+            file.query_lights(&query)
+        };
+
+        if let Err(err) = result {
+            let key = match err {
+                // An unknown scene being rendered is reported when the geometry is checked
+                QueryError::UnknownScene {..} if scene.is_none() => return,
+                QueryError::UnknownScene {..} => format!("{}.scene", key),
+                _ if name.is_some() => format!("{}.name", key),
+                _ => key.to_string(),
+            };
+            self.problem(key, err.to_string());
+        }
+    }
+
+    fn check_camera_follow(
+        &mut self,
+        key: &str,
+        file: &Arc<Mutex<File>>,
+        camera_follow: &Option<config::CameraFollow>,
+        scene: Option<&String>,
+    ) {
+        let config::CameraFollow {node, axes} = match camera_follow {
+            Some(follow) => follow,
+            None => return,
+        };
+
+        if axes.is_empty() {
+            self.problem(format!("{}.axes", key), "The camera must follow the node along at least one axis");
+        }
+
+        let query = NodeQuery {name: node.clone(), scene: scene.cloned(), animation: None};
+        let result = {
+            let file = file.lock().expect("bug: file lock was poisoned");
+            file.query_node_transform(&query)
+        };
+
+        match result {
+            // An unknown scene being rendered is reported when the geometry is checked
+            Err(QueryError::UnknownScene {..}) => {},
+            Err(err) => self.problem(format!("{}.node", key), err.to_string()),
+            Ok(_) => {},
+        }
+    }
+
+    fn open(&mut self, key: &str, path: &UnresolvedPath) -> Option<Arc<Mutex<File>>> {
+        let path = path.resolve(self.base_dir);
+        let result = self.file_cache.open(&path);
+        self.opened(key, &path, result)
+    }
+
+    fn open_gltf(&mut self, key: &str, path: &UnresolvedPath) -> Option<Arc<Mutex<File>>> {
+        let path = path.resolve(self.base_dir);
+        let result = self.file_cache.open_gltf(&path);
+        self.opened(key, &path, result)
+    }
+
+    fn opened(
+        &mut self,
+        key: &str,
+        path: &Path,
+        result: Result<Arc<Mutex<File>>, FileError>,
+    ) -> Option<Arc<Mutex<File>>> {
+        match result {
+            Ok(file) => Some(file),
+            Err(err) => {
+                self.problem(key.to_string(), format!("Unable to open `{}`: {}", path.display(), err));
+                None
+            },
+        }
+    }
+}
+
+/// Returns true if the given animation name is the animation that the layers of the query are
+/// blended on top of
+fn is_main_animation(query: &GeometryQuery, name: &str) -> bool {
+    query.animation.as_ref().and_then(|anim| anim.name.as_deref()) == Some(name)
+}
+
+/// Returns true if the given node name is the node locked by the root motion lock of the query
+fn is_root_motion_node(query: &GeometryQuery, name: &str) -> bool {
+    query.animation.as_ref()
+        .and_then(|anim| anim.root_motion.as_ref())
+        .and_then(|lock| lock.node.as_deref()) == Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks a spritesheet that draws the given animations of the bigboi sample
+    fn check_bigboi_sheet(animations: &str) -> Vec<ConfigProblem> {
+        let conf_str = format!(r#"
+            [[spritesheets]]
+            path = "spritesheet.png"
+            cell_width = 64
+            cell_height = 64
+            {}
+        "#, animations);
+        let config: TaskConfig = toml::from_str(&conf_str).unwrap();
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("samples/bigboi");
+        check_config(&config, &base_dir)
+    }
+
+    /// Checks a single animation of the bigboi sample with the given frames and extra options
+    fn check_bigboi_frames(frames: &str, options: &str) -> Vec<ConfigProblem> {
+        check_bigboi_sheet(&format!(r#"
+            [[spritesheets.animations]]
+            frames = {}
+            frame_width = 64
+            frame_height = 64
+            camera = {{ name = "front" }}
+            {}
+        "#, frames, options))
+    }
+
+    fn problem(key: &str, message: &str) -> ConfigProblem {
+        ConfigProblem {key: key.to_string(), message: message.to_string()}
+    }
+
+    #[test]
+    fn valid_animation() {
+        assert_eq!(check_bigboi_frames(r#"{ gltf = "bigboi.gltf", animation = "walk", steps = 4 }"#, ""), vec![]);
+    }
+
+    #[test]
+    fn empty_animations() {
+        assert_eq!(check_bigboi_sheet("animations = []"), vec![
+            problem("spritesheets[0].animations", "Spritesheet must have at least one animation"),
+        ]);
+    }
+
+    #[test]
+    fn unknown_animation() {
+        let problems = check_bigboi_frames(r#"{ gltf = "bigboi.gltf", animation = "run", steps = 4 }"#, "");
+        assert_eq!(problems, vec![
+            problem("spritesheets[0].animations[0].frames.animation", "Could not find animation named `run` in model file"),
+        ]);
+    }
+
+    #[test]
+    fn unknown_node() {
+        let problems = check_bigboi_frames(
+            r#"{ gltf = "bigboi.gltf", steps = 4 }"#,
+            r#"camera_follow = { node = "tail" }"#,
+        );
+        assert_eq!(problems, vec![
+            problem("spritesheets[0].animations[0].camera_follow.node", "Could not find node named `tail` in model file"),
+        ]);
+    }
+
+    #[test]
+    fn unknown_layer_animation_and_mask() {
+        let frames = r#"{ gltf = "bigboi.gltf", animation = "walk", steps = 4, layers = [
+            { animation = "walk", mask = "spine" },
+            { animation = "x" },
+        ] }"#;
+        assert_eq!(check_bigboi_frames(frames, ""), vec![
+            problem("spritesheets[0].animations[0].frames.layers[1].animation", "Could not find animation named `x` in model file"),
+        ]);
+
+        let frames = r#"{ gltf = "bigboi.gltf", animation = "walk", steps = 4, layers = [
+            { animation = "walk", mask = "spine" },
+            { animation = "walk", mask = "tail" },
+        ] }"#;
+        assert_eq!(check_bigboi_frames(frames, ""), vec![
+            problem("spritesheets[0].animations[0].frames.layers[1].mask", "Could not find node named `tail` in model file"),
+        ]);
+    }
+
+    #[test]
+    fn scene_with_all_scenes() {
+        let problems = check_bigboi_frames(r#"{ gltf = "bigboi.gltf", scene = "Scene", all_scenes = true, steps = 4 }"#, "");
+        assert_eq!(problems, vec![
+            problem("spritesheets[0].animations[0].frames.all_scenes", "Only one of `scene` or `all_scenes` may be provided"),
+        ]);
+    }
+}
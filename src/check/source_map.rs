@@ -0,0 +1,220 @@
+use std::fmt;
+use std::collections::HashMap;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor, MapAccess, SeqAccess};
+use toml::Spanned;
+
+/// A position in a configuration file
+///
+/// Both the line and the column start at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+/// A mapping from the keys in a configuration file to the location of their values
+///
+/// Keys are written the same way they would be accessed in code. For example, the camera of the
+/// second animation of the first spritesheet is `spritesheets[0].animations[1].camera`.
+#[derive(Debug)]
+pub struct SourceMap {
+    /// The byte offset of the start of each value, indexed by key
+    offsets: HashMap<String, usize>,
+    /// The byte offset of the start of each line
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    /// Parses the given TOML source, recording the location of every value
+    pub fn parse(source: &str) -> Result<Self, toml::de::Error> {
+        let root: SpannedNode = toml::from_str(source)?;
+
+        let mut offsets = HashMap::new();
+        root.record("", &mut offsets);
+
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+
+        Ok(Self {offsets, line_starts})
+    }
+
+    /// Returns the location of the value with the given key
+    ///
+    /// If that key is not present in the file (e.g. because the value was left as its default), the
+    /// location of the closest parent key is returned instead. Returns None if no location could
+    /// be found at all.
+    pub fn locate(&self, key: &str) -> Option<SourceLocation> {
+        let mut key = key;
+        loop {
+            if let Some(&offset) = self.offsets.get(key) {
+                return Some(self.location_of(offset));
+            }
+
+            key = parent_key(key)?;
+        }
+    }
+
+    /// Converts a byte offset into the source to a line and column
+    pub fn location_of(&self, offset: usize) -> SourceLocation {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+
+        SourceLocation {
+            line: line + 1,
+            col: offset - self.line_starts[line] + 1,
+        }
+    }
+}
+
+/// Returns the key that contains the given key, or None if the key is at the top level
+fn parent_key(key: &str) -> Option<&str> {
+    key.rfind(&['.', '['][..]).map(|i| &key[..i])
+}
+
+/// A TOML value that only keeps track of where it and its children are in the source
+enum SpannedNode {
+    Table(Vec<(Spanned<String>, Spanned<SpannedNode>)>),
+    Array(Vec<Spanned<SpannedNode>>),
+    Value,
+}
+
+impl SpannedNode {
+    /// Records the offset of each child of this node and returns the offset of the first key or
+    /// value in this node, if any
+    fn record(&self, key: &str, offsets: &mut HashMap<String, usize>) -> Option<usize> {
+        use SpannedNode::*;
+        match self {
+            Table(entries) => {
+                let mut first = None;
+                for (entry_key, value) in entries {
+                    let child_key = if key.is_empty() {
+                        entry_key.get_ref().clone()
+                    } else {
+                        format!("{}.{}", key, entry_key.get_ref())
+                    };
+
+                    // Tables declared with a `[header]` have no span of their own, so the best we
+                    // can do is point at their key
+                    let offset = value.get_ref().record(&child_key, offsets);
+                    let offset = match value.span() {
+                        (0, 0) => offset.unwrap_or_else(|| entry_key.start()),
+                        (start, _) => start,
+                    };
+                    offsets.insert(child_key, offset);
+
+                    let key_offset = entry_key.start();
+                    first = Some(first.map_or(key_offset, |first: usize| first.min(key_offset)));
+                }
+                first
+            },
+
+            Array(items) => {
+                let mut first = None;
+                for (i, item) in items.iter().enumerate() {
+                    let child_key = format!("{}[{}]", key, i);
+
+                    // Elements of an array of tables (`[[header]]`) have no span either, so use
+                    // the location of their first value
+                    let offset = item.get_ref().record(&child_key, offsets);
+                    let offset = match (item.span(), offset) {
+                        ((0, 0), Some(offset)) => offset,
+                        ((start, _), _) => start,
+                    };
+                    offsets.insert(child_key, offset);
+
+                    first = Some(first.map_or(offset, |first: usize| first.min(offset)));
+                }
+                first
+            },
+
+            Value => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SpannedNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SpannedNodeVisitor)
+    }
+}
+
+struct SpannedNodeVisitor;
+
+impl<'de> Visitor<'de> for SpannedNodeVisitor {
+    type Value = SpannedNode;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any valid TOML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Self::Value, E> { Ok(SpannedNode::Value) }
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<Self::Value, E> { Ok(SpannedNode::Value) }
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<Self::Value, E> { Ok(SpannedNode::Value) }
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> { Ok(SpannedNode::Value) }
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<Self::Value, E> { Ok(SpannedNode::Value) }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(SpannedNode::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(SpannedNode::Table(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"[[spritesheets]]
+path = "sheet.png"
+
+[[spritesheets.animations]]
+frames = { gltf = "model.gltf", steps = 5 }
+camera = { name = "front" }
+
+[[poses]]
+model = "model.obj"
+"#;
+
+    #[test]
+    fn locate_values() {
+        let source_map = SourceMap::parse(SOURCE).unwrap();
+
+        assert_eq!(source_map.locate("spritesheets[0].path"), Some(SourceLocation {line: 2, col: 8}));
+        assert_eq!(source_map.locate("spritesheets[0].animations[0].frames.gltf"),
+            Some(SourceLocation {line: 5, col: 19}));
+        assert_eq!(source_map.locate("spritesheets[0].animations[0].camera.name"),
+            Some(SourceLocation {line: 6, col: 19}));
+        assert_eq!(source_map.locate("poses[0].model"), Some(SourceLocation {line: 9, col: 9}));
+    }
+
+    #[test]
+    fn locate_missing_values() {
+        let source_map = SourceMap::parse(SOURCE).unwrap();
+
+        // Falls back to the closest parent that is present
+        assert_eq!(source_map.locate("spritesheets[0].animations[0].frames.animation"),
+            Some(SourceLocation {line: 5, col: 10}));
+        assert_eq!(source_map.locate("poses[0].camera.name"), Some(SourceLocation {line: 9, col: 1}));
+        assert_eq!(source_map.locate("missing"), None);
+    }
+}
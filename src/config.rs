@@ -2,7 +2,8 @@ use std::num::NonZeroU32;
 
 use serde::{Serialize, Deserialize};
 
-use crate::math::{Vec3, Rgba, Degrees, Milliseconds};
+use crate::math::{Vec2, Vec3, Rgb, Rgba, Degrees, Milliseconds};
+use crate::tasks;
 
 // PathBuf is not imported to avoid its use in this module. Every path in this module should
 // be an UnresolvedPath.
@@ -87,6 +88,10 @@ pub struct TaskConfig {
 pub struct Spritesheet {
     /// The path to output the generated spritesheet, relative to configuration file
     pub path: UnresolvedPath,
+    /// The path to output a JSON file describing each frame of the spritesheet (e.g. the
+    /// attachment points and hitboxes), relative to configuration file (default: no file is
+    /// written)
+    pub metadata_path: Option<UnresolvedPath>,
     /// The width of each cell in the spritesheet in pixels
     pub cell_width: NonZeroU32,
     /// The height of each cell in the spritesheet in pixels
@@ -100,21 +105,72 @@ pub struct Spritesheet {
     /// The background color of the spritesheet (default: transparent black)
     #[serde(default = "default_background")]
     pub background: Rgba,
+    /// How the colors produced by lighting are encoded in the spritesheet. Colors in the
+    /// configuration (e.g. the background) are written to the image exactly as typed, so they
+    /// are assumed to use the same encoding. (default: Srgb)
+    #[serde(default)]
+    pub color_transfer: ColorTransfer,
+    /// How the alpha channel of the generated image is written (default: straight alpha with no
+    /// cleanup)
+    #[serde(default)]
+    pub alpha: AlphaOutput,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Animation {
+    /// The name of the animation in the metadata file of the spritesheet. Must be unique within
+    /// the spritesheet. (default: the index of the animation, e.g. "0")
+    pub name: Option<String>,
     pub frames: AnimationFrames,
+    /// The path to output an animated GIF of the frames of this animation, relative to
+    /// configuration file. Each frame is shown for its duration, or for 100ms if its duration is
+    /// not known. (default: no GIF is written)
+    pub gif_path: Option<UnresolvedPath>,
     /// The width at which to render each frame (in pixels)
     pub frame_width: NonZeroU32,
     /// The height at which to render each frame (in pixels)
     pub frame_height: NonZeroU32,
     /// The camera perspective from which to render each frame
     pub camera: PresetCamera,
+    /// The lights in the model file used to light each frame. The lights move with the animation
+    /// being rendered, but their color and intensity are not animated since glTF animations can
+    /// only move nodes. (default: a single white directional light)
+    pub lights: Option<ModelLights>,
+    /// Moves the camera along with a node of the model as the animation plays. Only used with
+    /// glTF animation frames. (default: the camera does not move)
+    pub camera_follow: Option<CameraFollow>,
     /// The outline to use when drawing each frame. (default: no outline)
     #[serde(default)]
     pub outline: Outline,
+    /// Other models to draw in each frame along with the animated model (default: no models)
+    #[serde(default)]
+    pub extra_models: Vec<ExtraModel>,
+    /// The shadows cast by the lights in each frame (default: no shadows)
+    pub shadows: Option<Shadows>,
+    /// A rim light drawn on every material (default: no rim light)
+    pub rim_light: Option<RimLight>,
+    /// A specular highlight drawn on every material (default: no specular highlight)
+    pub specular: Option<Specular>,
+    /// Rim lights and specular highlights for specific materials (default: every material uses
+    /// `rim_light` and `specular`)
+    #[serde(default)]
+    pub materials: Vec<MaterialHighlights>,
+    /// The maximum number of lights used to light each model. When a scene has more lights than
+    /// this, only the lights that contribute the most at the origin of each model are used. That
+    /// may not be where an animated (skinned) model actually is. Value must be greater than zero.
+    /// (default: every light is used)
+    pub max_lights: Option<NonZeroU32>,
+    /// A point whose pixel position is recorded for each frame (default: no pivot)
+    pub pivot: Option<Pivot>,
+    /// Points whose pixel positions are written to the metadata file for each frame (default: no
+    /// attachment points)
+    #[serde(default)]
+    pub attachment_points: Vec<AttachmentPoint>,
+    /// Meshes whose pixel bounding boxes are written to the metadata file for each frame
+    /// (default: no hitboxes)
+    #[serde(default)]
+    pub hitboxes: Vec<Hitbox>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,6 +180,12 @@ pub enum AnimationFrames {
     GltfFrames {
         /// The path to a glTF file
         gltf: UnresolvedPath,
+        /// The name of the scene to render (default: the default scene of the glTF file)
+        scene: Option<String>,
+        /// If true, the animation is rendered once for every scene in the file, with each scene
+        /// in its own row. Cannot be used with `scene`. (default: false)
+        #[serde(default)]
+        all_scenes: bool,
         /// The name of the animation to select. Can be omitted if there is only a single animation
         animation: Option<String>,
         /// The "global" animation time in ms at which to start the animation (default: 0.0)
@@ -134,8 +196,64 @@ pub enum AnimationFrames {
         end_time: Option<Milliseconds>,
         /// The number of steps to take between the start and end time.
         ///
-        /// This is the number of frames that will be drawn in the spritesheet.
-        steps: NonZeroU32,
+        /// This is the number of frames that will be sampled from the animation. Exactly one of
+        /// `steps` or `fps` must be provided.
+        steps: Option<NonZeroU32>,
+        /// The frame rate to sample the animation at. The number of steps is derived from the
+        /// time between the start and end time. Exactly one of `steps` or `fps` must be provided.
+        fps: Option<f32>,
+        /// How the steps are spaced out between the start and end time (default: Linear)
+        #[serde(default)]
+        easing: Easing,
+        /// If true, the end time is not sampled. Use this for looping animations whose last
+        /// keyframe is the same as their first keyframe. (default: false)
+        #[serde(default)]
+        loop_safe: bool,
+        /// A fraction of the animation (e.g. 0.25) to shift every step by. Steps that go past the
+        /// end time wrap around to the start time. Usually combined with `loop_safe`.
+        /// (default: 0.0)
+        #[serde(default)]
+        loop_offset: f32,
+        /// The order in which the sampled frames are drawn (default: Forward)
+        #[serde(default)]
+        playback: Playback,
+        /// Frames to repeat so that they are shown for longer (default: no holds)
+        #[serde(default)]
+        holds: Vec<Hold>,
+        /// Additional animations to blend on top of the selected animation, in order. Each layer
+        /// is sampled at the same time as the selected animation. (default: no layers)
+        #[serde(default)]
+        layers: Vec<AnimationLayer>,
+        /// Keeps the model in place while the animation plays (default: the animation is played
+        /// as is)
+        root_motion: Option<RootMotion>,
+    },
+    GltfFrameTimes {
+        /// The path to a glTF file
+        gltf: UnresolvedPath,
+        /// The name of the scene to render (default: the default scene of the glTF file)
+        scene: Option<String>,
+        /// If true, the animation is rendered once for every scene in the file, with each scene
+        /// in its own row. Cannot be used with `scene`. (default: false)
+        #[serde(default)]
+        all_scenes: bool,
+        /// The name of the animation to select. Can be omitted if there is only a single animation
+        animation: Option<String>,
+        /// The "global" animation time in ms of each frame to sample, in order
+        times: Vec<Milliseconds>,
+        /// The order in which the sampled frames are drawn (default: Forward)
+        #[serde(default)]
+        playback: Playback,
+        /// Frames to repeat so that they are shown for longer (default: no holds)
+        #[serde(default)]
+        holds: Vec<Hold>,
+        /// Additional animations to blend on top of the selected animation, in order. Each layer
+        /// is sampled at the same time as the selected animation. (default: no layers)
+        #[serde(default)]
+        layers: Vec<AnimationLayer>,
+        /// Keeps the model in place while the animation plays (default: the animation is played
+        /// as is)
+        root_motion: Option<RootMotion>,
     },
     /// An array of filenames. OBJ files will be used as is. For glTF files, the scene will be used
     /// as loaded regardless of the animations present in the file.
@@ -143,16 +261,209 @@ pub enum AnimationFrames {
 }
 
 impl AnimationFrames {
-    /// Returns the number of frame images to be created
-    pub fn len(&self) -> u32 {
+    /// Returns the number of frame images to be created, or None if that depends on the length of
+    /// the animation in the model file (i.e. `fps` is used without an `end_time`) or if the
+    /// frames are not configured correctly
+    pub fn len(&self) -> Option<u32> {
         use AnimationFrames::*;
         match self {
-            GltfFrames {steps, ..} => steps.get(),
-            Models(models) => models.len() as u32,
+            GltfFrames {start_time, end_time, steps, fps, loop_safe, playback, holds, ..} => {
+                let steps = match (steps, fps, end_time) {
+                    (Some(steps), None, _) => *steps,
+                    (None, Some(fps), Some(end_time)) => {
+                        tasks::fps_steps(*start_time, *end_time, *fps, *loop_safe)?.0
+                    },
+                    _ => return None,
+                };
+
+                Some(with_holds(playback.len(steps.get()), holds))
+            },
+            GltfFrameTimes {times, playback, holds, ..} => {
+                Some(with_holds(playback.len(times.len() as u32), holds))
+            },
+            Models(models) => Some(models.len() as u32),
+        }
+    }
+}
+
+/// Returns the number of frames after the given holds are applied. Holds for frames that do not
+/// exist are ignored.
+fn with_holds(frames: u32, holds: &[Hold]) -> u32 {
+    frames + holds.iter()
+        .filter(|hold| hold.frame < frames)
+        .map(|hold| hold.count.get() - 1)
+        .sum::<u32>()
+}
+
+/// The spacing of the steps sampled between a start and end time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Easing {
+    /// Evenly spaced steps
+    #[default]
+    Linear,
+    /// Steps start close together and spread out towards the end time
+    EaseIn,
+    /// Steps start spread out and get closer together towards the end time
+    EaseOut,
+    /// Steps are close together near both the start and end time
+    EaseInOut,
+}
+
+/// The order in which sampled frames are drawn
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Playback {
+    /// The frames are drawn in the order they were sampled
+    #[default]
+    Forward,
+    /// The frames are drawn from last to first
+    Reverse,
+    /// The frames are drawn forward and then backward. The first and last frames are not repeated
+    /// so the result can be looped.
+    PingPong,
+}
+
+impl Playback {
+    /// Returns the number of frames drawn for the given number of sampled frames
+    pub fn len(self, samples: u32) -> u32 {
+        use Playback::*;
+        match self {
+            Forward | Reverse => samples,
+            PingPong if samples > 2 => samples * 2 - 2,
+            PingPong => samples,
         }
     }
 }
 
+/// Locks the translation of a node so that an animation that moves the model plays in place
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RootMotion {
+    /// The name of the node (e.g. a bone) to lock. (default: the root nodes of the model)
+    pub node: Option<String>,
+    /// The axes to lock the translation on (default: ["X", "Z"], the ground plane)
+    #[serde(default = "default_root_motion_axes")]
+    pub axes: Vec<Axis>,
+    /// If true, the translation removed from each frame is written to the metadata file of the
+    /// spritesheet so the motion can be applied by the game instead. Requires the spritesheet to
+    /// have a `metadata_path`. Only one offset is written per frame, so when `node` is not set and
+    /// the animation moves more than one root node by different amounts, rendering fails and
+    /// `node` must be set to choose which node's offset to export. (default: false)
+    #[serde(default)]
+    pub export_offsets: bool,
+}
+
+/// Keeps a node of the model in the same place in each frame by moving the camera with it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CameraFollow {
+    /// The name of the node to follow (e.g. the hips of a character)
+    pub node: String,
+    /// The world axes along which the camera follows the node. The camera stays in place along
+    /// the other axes. (default: ["X", "Y", "Z"])
+    #[serde(default = "default_follow_axes")]
+    pub axes: Vec<Axis>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+/// A frame that is repeated so that it is shown for longer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Hold {
+    /// The index of the frame to repeat, after the playback order has been applied
+    pub frame: u32,
+    /// The total number of times the frame is drawn
+    pub count: NonZeroU32,
+}
+
+/// An animation blended on top of another animation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationLayer {
+    /// The name of the animation to blend in
+    pub animation: String,
+    /// How much this animation replaces the animations beneath it, from 0.0 (no effect) to 1.0
+    /// (fully replaced). (default: 1.0)
+    #[serde(default = "default_layer_weight")]
+    pub weight: f32,
+    /// The name of a node in the model. Only that node and its children will be affected by this
+    /// animation. (default: the entire model is affected)
+    pub mask: Option<String>,
+}
+
+/// A point that game engines use to position a sprite (e.g. the feet of a character)
+///
+/// The pixel position of the pivot in each frame is written to the metadata file, so a
+/// `metadata_path` is required unless `align_to` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Pivot {
+    /// The name of a node (e.g. the root bone) that the pivot moves along with. Only glTF files
+    /// contain nodes. (default: the pivot does not move)
+    pub node: Option<String>,
+    /// The position of the pivot in world coordinates, or in the coordinates of the node if a
+    /// node is given (default: the origin)
+    #[serde(default = "Vec3::zero")]
+    pub offset: Vec3,
+    /// If set, each frame is shifted so that the pivot is drawn at this pixel position, measured
+    /// from the top left corner of the frame (default: frames are not shifted)
+    pub align_to: Option<Vec2>,
+}
+
+/// A named point on the model (e.g. a hand or the muzzle of a gun) that a game can attach
+/// things to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AttachmentPoint {
+    /// The name of the point in the metadata file. Must be unique within the animation or pose.
+    pub name: String,
+    /// The name of the node (e.g. a bone or an empty) that the point moves along with
+    pub node: String,
+    /// The position of the point in the coordinates of the node (default: the origin of the node)
+    #[serde(default = "Vec3::zero")]
+    pub offset: Vec3,
+}
+
+/// A named region of the model (e.g. a hurtbox) whose size and position change with each frame
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Hitbox {
+    /// The name of the hitbox in the metadata file. Must be unique within the animation or pose.
+    pub name: String,
+    /// The name of the node with the mesh that the hitbox surrounds (e.g. a simple box that
+    /// follows the bones of a character)
+    pub node: String,
+}
+
+/// A model drawn along with the main model of an animation or pose (e.g. a prop or a floor tile)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExtraModel {
+    /// The path to an OBJ or glTF file
+    pub model: UnresolvedPath,
+    /// The name of the scene to draw (default: the default scene of the model file)
+    pub scene: Option<String>,
+    /// The name of an animation of this model to play. It is sampled at the same position in the
+    /// animation as each frame. Only used with glTF animation frames and poses.
+    /// (default: the model is drawn as loaded)
+    pub animation: Option<String>,
+    /// The name of a node (e.g. a bone) of the main model to attach this model to. The model
+    /// moves along with the node in each frame. (default: the model is not attached)
+    pub attach_to: Option<String>,
+    /// The amount to move the model by in world coordinates, or in the coordinates of the node
+    /// that the model is attached to (default: no offset)
+    #[serde(default = "Vec3::zero")]
+    pub offset: Vec3,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Pose {
@@ -160,12 +471,19 @@ pub struct Pose {
     pub model: PoseModel,
     /// The path to output the generated image, relative to configuration file
     pub path: UnresolvedPath,
+    /// The path to output a JSON file describing the generated image (e.g. the attachment points
+    /// and hitboxes), relative to configuration file (default: no file is written)
+    pub metadata_path: Option<UnresolvedPath>,
     /// The width at which to render each frame (in pixels)
     pub width: NonZeroU32,
     /// The height at which to render each frame (in pixels)
     pub height: NonZeroU32,
     /// The camera perspective from which to render each frame
     pub camera: PresetCamera,
+    /// The lights in the model file used to light the pose. The lights are moved by the animation
+    /// of the pose, but their color and intensity are not animated since glTF animations can only
+    /// move nodes. (default: a single white directional light)
+    pub lights: Option<ModelLights>,
     /// A scale factor to apply to the generated image. The image is scaled without interpolation.
     /// The value must be greater than zero. (default: 1).
     #[serde(default = "default_scale_factor")]
@@ -173,9 +491,46 @@ pub struct Pose {
     /// The background color of the generated image (default: transparent black)
     #[serde(default = "default_background")]
     pub background: Rgba,
+    /// How the colors produced by lighting are encoded in the generated image. Colors in the
+    /// configuration (e.g. the background) are written to the image exactly as typed, so they
+    /// are assumed to use the same encoding. (default: Srgb)
+    #[serde(default)]
+    pub color_transfer: ColorTransfer,
+    /// How the alpha channel of the generated image is written (default: straight alpha with no
+    /// cleanup)
+    #[serde(default)]
+    pub alpha: AlphaOutput,
     /// The outline to use when drawing the generated image. (default: no outline)
     #[serde(default)]
     pub outline: Outline,
+    /// Other models to draw along with the posed model (default: no models)
+    #[serde(default)]
+    pub extra_models: Vec<ExtraModel>,
+    /// The shadows cast by the lights in the generated image (default: no shadows)
+    pub shadows: Option<Shadows>,
+    /// A rim light drawn on every material (default: no rim light)
+    pub rim_light: Option<RimLight>,
+    /// A specular highlight drawn on every material (default: no specular highlight)
+    pub specular: Option<Specular>,
+    /// Rim lights and specular highlights for specific materials (default: every material uses
+    /// `rim_light` and `specular`)
+    #[serde(default)]
+    pub materials: Vec<MaterialHighlights>,
+    /// The maximum number of lights used to light each model. When a scene has more lights than
+    /// this, only the lights that contribute the most at the origin of each model are used. That
+    /// may not be where an animated (skinned) model actually is. Value must be greater than zero.
+    /// (default: every light is used)
+    pub max_lights: Option<NonZeroU32>,
+    /// A point whose pixel position is recorded for each frame (default: no pivot)
+    pub pivot: Option<Pivot>,
+    /// Points whose pixel positions are written to the metadata file for each frame (default: no
+    /// attachment points)
+    #[serde(default)]
+    pub attachment_points: Vec<AttachmentPoint>,
+    /// Meshes whose pixel bounding boxes are written to the metadata file for each frame
+    /// (default: no hitboxes)
+    #[serde(default)]
+    pub hitboxes: Vec<Hitbox>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -185,6 +540,8 @@ pub enum PoseModel {
     GltfFrame {
         /// The path to a glTF file
         gltf: UnresolvedPath,
+        /// The name of the scene to render (default: the default scene of the glTF file)
+        scene: Option<String>,
         /// The name of the animation to select. Can be omitted if there is only a single animation
         /// or if there is no animation.
         animation: Option<String>,
@@ -192,6 +549,10 @@ pub enum PoseModel {
         /// of the animation, or the default pose of the model if there is no animation.
         #[serde(default)]
         time: Milliseconds,
+        /// Additional animations to blend on top of the selected animation, in order. Each layer
+        /// is sampled at the same time as the selected animation. (default: no layers)
+        #[serde(default)]
+        layers: Vec<AnimationLayer>,
     },
     /// A single filename. An OBJ file will be used as is. For a glTF file, the model will be
     /// rendered as loaded regardless of the animations present in the file.
@@ -218,6 +579,187 @@ impl Default for Outline {
     }
 }
 
+/// Shadows cast by the first directional or spot light
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct Shadows {
+    /// The color that the darkest band of the cel shading is multiplied by. This is used for
+    /// surfaces facing away from the light and for surfaces in shadow. (default: [0.1, 0.1, 0.1])
+    pub tint: Rgb,
+    /// The width, height, and depth of the region around the origin that can have shadows. Value
+    /// must be greater than zero. (default: 10.0)
+    pub extent: f32,
+    /// The width and height of the texture used to store the shadows (default: 1024)
+    pub map_size: NonZeroU32,
+    /// A shadow drawn on an invisible ground plane below the model. Only the shadow is visible,
+    /// the background is left as is. (default: no drop shadow)
+    pub drop_shadow: Option<DropShadow>,
+}
+
+impl Default for Shadows {
+    fn default() -> Self {
+        Self {
+            tint: Rgb::broadcast(0.1),
+            extent: 10.0,
+            map_size: NonZeroU32::new(1024).unwrap(),
+            drop_shadow: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DropShadow {
+    /// The kind of drop shadow to draw (default: Projected)
+    pub style: DropShadowStyle,
+    /// The position of the ground plane along the world y-axis (default: 0.0)
+    pub ground_height: f32,
+    /// The color of the drop shadow. The alpha component controls its opacity.
+    /// (default: black with an alpha of 0.5)
+    pub color: Rgba,
+    /// The radius of the circle drawn for a Blob drop shadow. Value must be greater than zero.
+    /// (default: 1.0)
+    pub radius: f32,
+}
+
+impl Default for DropShadow {
+    fn default() -> Self {
+        Self {
+            style: DropShadowStyle::default(),
+            ground_height: 0.0,
+            color: Rgba {r: 0.0, g: 0.0, b: 0.0, a: 0.5},
+            radius: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum DropShadowStyle {
+    /// The shadow that the light used for shadows casts onto the ground
+    #[default]
+    Projected,
+    /// A circle on the ground, centered below the origin
+    Blob,
+}
+
+/// How the alpha channel of a generated image is written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct AlphaOutput {
+    /// How the alpha of each pixel is stored (default: Straight)
+    pub mode: AlphaMode,
+    /// The minimum alpha of the pixels that become opaque in the Threshold mode, between 0.0 and
+    /// 1.0 (default: 0.5)
+    pub threshold: f32,
+    /// If true, partially transparent pixels along the edges of the sprites (e.g. from the
+    /// drop shadow) are removed and fully transparent pixels are cleared to transparent black, so
+    /// the sprites composite cleanly over any background (default: false)
+    pub remove_fringe: bool,
+}
+
+impl Default for AlphaOutput {
+    fn default() -> Self {
+        Self {
+            mode: AlphaMode::default(),
+            threshold: 0.5,
+            remove_fringe: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum AlphaMode {
+    /// The color of each pixel is stored separately from its alpha
+    #[default]
+    Straight,
+    /// The color of each pixel is multiplied by its alpha
+    Premultiplied,
+    /// Every pixel is either fully opaque or fully transparent, based on `threshold`
+    Threshold,
+}
+
+/// The transfer function used to encode the colors in a generated image
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum ColorTransfer {
+    /// The sRGB transfer function, expected by most image viewers and editors
+    #[default]
+    Srgb,
+    /// Colors are written without any encoding
+    Linear,
+    /// Colors are raised to the power of 1/gamma. Value must be greater than zero.
+    Gamma(f32),
+}
+
+/// A hard edged band of light along the silhouette of a model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct RimLight {
+    /// The color of the rim light. It is multiplied by the color of each light. (default: white)
+    pub color: Rgb,
+    /// The width of the rim, between 0.0 (no rim) and 1.0 (the entire surface). (default: 0.3)
+    pub width: f32,
+    /// The minimum amount of light that a surface must receive to have a rim, between 0.0 and
+    /// 1.0 (default: 0.1)
+    pub threshold: f32,
+}
+
+impl Default for RimLight {
+    fn default() -> Self {
+        Self {
+            color: Rgb::white(),
+            width: 0.3,
+            threshold: 0.1,
+        }
+    }
+}
+
+/// A single step specular highlight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct Specular {
+    /// The color of the highlight. It is multiplied by the color of each light. (default: white,
+    /// tinted by the color of metallic materials)
+    pub color: Option<Rgb>,
+    /// The specular exponent. Higher values produce smaller highlights. Value must be greater
+    /// than zero. (default: based on the roughness of the material)
+    pub shininess: Option<f32>,
+    /// The minimum specular intensity drawn as part of the highlight, between 0.0 and 1.0
+    /// (default: 0.5)
+    pub threshold: f32,
+}
+
+impl Default for Specular {
+    fn default() -> Self {
+        Self {
+            color: None,
+            shininess: None,
+            threshold: 0.5,
+        }
+    }
+}
+
+/// The rim light and specular highlight for a material with a specific name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaterialHighlights {
+    /// The name of the material
+    pub name: String,
+    /// The rim light to draw on this material. Set the width to 0.0 to remove the rim light.
+    /// (default: the rim light configured for every material)
+    pub rim_light: Option<RimLight>,
+    /// The specular highlight to draw on this material. Set the threshold to 1.0 to remove the
+    /// highlight. (default: the specular highlight configured for every material)
+    pub specular: Option<Specular>,
+}
+
 /// A number of present camera angles or a completely custom configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -245,7 +787,20 @@ pub enum Perspective {
 pub struct NamedCamera {
     /// The name of the camera in the 3D model file
     pub name: String,
-    /// The name of the scene to look for the camera in or None if the default scene should be used
+    /// The name of the scene to look for the camera in or None if the scene being rendered should
+    /// be used
+    pub scene: Option<String>,
+}
+
+/// Lights from the 3D model file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelLights {
+    /// The name of the light in the 3D model file, or None if every light in the scene should be
+    /// used
+    pub name: Option<String>,
+    /// The name of the scene to look for the lights in or None if the scene being rendered should
+    /// be used
     pub scene: Option<String>,
 }
 
@@ -301,6 +856,9 @@ impl From<Perspective> for Camera {
 
 fn default_scale_factor() -> NonZeroU32 { NonZeroU32::new(1).unwrap() }
 fn default_background() -> Rgba { Rgba {r: 0.0, g: 0.0, b: 0.0, a: 0.0} }
+fn default_layer_weight() -> f32 { 1.0 }
+fn default_root_motion_axes() -> Vec<Axis> { vec![Axis::X, Axis::Z] }
+fn default_follow_axes() -> Vec<Axis> { vec![Axis::X, Axis::Y, Axis::Z] }
 
 #[cfg(test)]
 mod tests {
@@ -25,6 +25,7 @@
 #![deny(unused_must_use)] // Ignoring a Result is usually a sign of trouble
 
 pub mod config;
+pub mod check;
 pub mod tasks;
 pub mod renderer;
 pub mod query3d;
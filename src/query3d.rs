@@ -1,5 +1,7 @@
 mod backend;
 mod query;
+mod info;
 
 pub use backend::*;
 pub use query::*;
+pub use info::*;
@@ -1,15 +1,18 @@
 pub mod obj;
 pub mod gltf;
 
+use std::fmt;
 use std::sync::Arc;
 use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 use glium::texture::TextureCreationError;
 
+use crate::math::{Mat4, Vec3, Milliseconds};
 use crate::renderer::{Display, ShaderGeometry, ShaderGeometryError, Camera, Light};
 
-use super::query::{GeometryQuery, CameraQuery, LightQuery};
+use super::query::{GeometryQuery, AnimationQuery, NodeQuery, CameraQuery, LightQuery};
+use super::info::ModelInfo;
 
 #[derive(Debug, Error)]
 pub enum QueryError {
@@ -20,17 +23,28 @@ pub enum QueryError {
 
     #[error("Could not find scene named `{name}` in model file")]
     UnknownScene {name: String},
+    #[error("Scene at index {index} in model file has no name, so it cannot be selected")]
+    UnnamedScene {index: usize},
 
     #[error("Could not find animation named `{name}` in model file")]
     UnknownAnimation {name: String},
     #[error("Could not find any matching animation in model file")]
     NoAnimationFound,
+    #[error("Multiple locked root nodes are moved by different amounts, please specify the node whose root motion offset should be exported")]
+    AmbiguousRootMotion,
     #[error("Multiple animations matched for a single node, please specify an animation name")]
     AmbiguousAnimation,
 
     #[error("Could not find any matching geometry in model file")]
     NoGeometryFound,
 
+    #[error("Could not find node named `{name}` in model file")]
+    UnknownNode {name: String},
+    #[error("Node named `{name}` in model file does not have a mesh")]
+    NodeHasNoMesh {name: String},
+    #[error("Mesh of node named `{name}` in model file uses joint {joint}, which is not in its skin")]
+    InvalidJointIndex {name: String, joint: usize},
+
     #[error("Could not find camera named `{name}` in model file")]
     UnknownCamera {name: String},
     #[error("Could not find any matching cameras in model file")]
@@ -46,11 +60,33 @@ pub trait QueryBackend {
     /// Attempts to find geometry matching the given query in this file. Only returns success
     /// if at least one geometry was found.
     fn query_geometry(&mut self, query: &GeometryQuery, display: &Display) -> Result<Arc<Vec<Arc<ShaderGeometry>>>, QueryError>;
+    /// Checks that `query_geometry` would find geometry matching the given query, without
+    /// uploading anything to the GPU.
+    fn check_geometry(&self, query: &GeometryQuery) -> Result<(), QueryError>;
     /// Attempts to find a camera matching the given query in this file.
     fn query_camera(&mut self, query: &CameraQuery) -> Result<Arc<Camera>, QueryError>;
     /// Attempts to find lights matching the given query in this file. Only returns success
     /// if at least one light was found.
     fn query_lights(&mut self, query: &LightQuery) -> Result<Arc<Vec<Arc<Light>>>, QueryError>;
+    /// Returns the name of every scene in this file, in order. Returns an error if a scene does not
+    /// have a name since it would not be possible to select it.
+    fn query_scene_names(&self) -> Result<Vec<String>, QueryError>;
+    /// Returns the time of the last keyframe of the animation with the given name (or the only
+    /// animation if `name` is None), across every node and channel it animates.
+    fn query_animation_end_time(&self, name: Option<&str>) -> Result<Milliseconds, QueryError>;
+    /// Returns the translation removed from the locked node by the root motion lock of the given
+    /// query, in the coordinate space of that node's parent. If multiple nodes are locked, every
+    /// node that the animation moves must be moved by the same amount.
+    ///
+    /// Only the nodes in the given scene (or the default scene if `scene` is None) are locked.
+    fn query_root_motion(&self, query: &AnimationQuery, scene: Option<&str>) -> Result<Vec3, QueryError>;
+    /// Returns the world transform of the node matching the given query
+    fn query_node_transform(&self, query: &NodeQuery) -> Result<Mat4, QueryError>;
+    /// Returns the world position of every vertex of the mesh of the node matching the given
+    /// query, after the mesh has been skinned
+    fn query_mesh_positions(&self, query: &NodeQuery) -> Result<Vec<Vec3>, QueryError>;
+    /// Returns a summary of everything in this file
+    fn inspect(&self) -> ModelInfo;
 }
 
 #[derive(Debug, Error)]
@@ -60,6 +96,61 @@ pub enum FileError {
     GltfError(#[from] ::gltf::Error),
     #[error("Unsupported file extension: {path:?}")]
     UnsupportedFileExtension {path: PathBuf},
+
+    #[error("The file must have at least one scene")]
+    NoScenes,
+
+    #[error("Node {node} must have a mesh in order to have a skin")]
+    SkinWithoutMesh {node: ItemRef},
+    #[error("Node {node} must only have one of a mesh, camera, or light")]
+    MultipleNodeData {node: ItemRef},
+
+    #[error("Primitive {primitive} of mesh {mesh} is missing its {attribute}")]
+    MissingPrimitiveAttribute {mesh: ItemRef, primitive: usize, attribute: &'static str},
+    #[error("Primitive {primitive} of mesh {mesh} has {count} {attribute} but {vertices} vertices")]
+    PrimitiveAttributeCount {mesh: ItemRef, primitive: usize, attribute: &'static str, count: usize, vertices: usize},
+
+    #[error("Material {material} uses TEXCOORD_{tex_coord}, but only TEXCOORD_0 is supported")]
+    UnsupportedTexCoord {material: ItemRef, tex_coord: u32},
+
+    #[error("Animation {animation} has a channel for node {node} with no sampler {values} values")]
+    MissingSamplerValues {animation: ItemRef, node: ItemRef, values: &'static str},
+    #[error("Animation {animation} has a keyframe time for node {node} that is not a finite number")]
+    NonFiniteKeyframeTime {animation: ItemRef, node: ItemRef},
+    #[error("Animation {animation} has multiple sets of {property} keyframes for node {node}")]
+    DuplicateKeyframes {animation: ItemRef, node: ItemRef, property: &'static str},
+    #[error("Animation {animation} uses the unsupported {interpolation} interpolation for node {node}")]
+    UnsupportedInterpolation {animation: ItemRef, node: ItemRef, interpolation: &'static str},
+    #[error("Animation {animation} animates the morph target weights of node {node}, which is not supported")]
+    UnsupportedMorphTargets {animation: ItemRef, node: ItemRef},
+}
+
+/// Identifies an item (e.g. a node or an animation) in a 3D file for use in error messages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemRef {
+    /// The index of the item in the file
+    pub index: usize,
+    /// The name of the item (if any)
+    pub name: Option<String>,
+}
+
+impl ItemRef {
+    pub fn new(index: usize, name: Option<&str>) -> Self {
+        Self {
+            index,
+            name: name.map(String::from),
+        }
+    }
+}
+
+impl fmt::Display for ItemRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name.as_deref() {
+            // Names are often empty, in which case they aren't very useful
+            Some(name) if !name.is_empty() => write!(f, "`{}` (index {})", name, self.index),
+            _ => write!(f, "at index {}", self.index),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -93,6 +184,14 @@ impl QueryBackend for File {
         }
     }
 
+    fn check_geometry(&self, query: &GeometryQuery) -> Result<(), QueryError> {
+        use File::*;
+        match self {
+            Obj(objs) => objs.check_geometry(query),
+            Gltf(gltf) => gltf.check_geometry(query),
+        }
+    }
+
     fn query_camera(&mut self, query: &CameraQuery) -> Result<Arc<Camera>, QueryError> {
         use File::*;
         match self {
@@ -108,4 +207,52 @@ impl QueryBackend for File {
             Gltf(gltf) => gltf.query_lights(query),
         }
     }
+
+    fn query_scene_names(&self) -> Result<Vec<String>, QueryError> {
+        use File::*;
+        match self {
+            Obj(objs) => objs.query_scene_names(),
+            Gltf(gltf) => gltf.query_scene_names(),
+        }
+    }
+
+    fn query_animation_end_time(&self, name: Option<&str>) -> Result<Milliseconds, QueryError> {
+        use File::*;
+        match self {
+            Obj(objs) => objs.query_animation_end_time(name),
+            Gltf(gltf) => gltf.query_animation_end_time(name),
+        }
+    }
+
+    fn query_root_motion(&self, query: &AnimationQuery, scene: Option<&str>) -> Result<Vec3, QueryError> {
+        use File::*;
+        match self {
+            Obj(objs) => objs.query_root_motion(query, scene),
+            Gltf(gltf) => gltf.query_root_motion(query, scene),
+        }
+    }
+
+    fn query_node_transform(&self, query: &NodeQuery) -> Result<Mat4, QueryError> {
+        use File::*;
+        match self {
+            Obj(objs) => objs.query_node_transform(query),
+            Gltf(gltf) => gltf.query_node_transform(query),
+        }
+    }
+
+    fn query_mesh_positions(&self, query: &NodeQuery) -> Result<Vec<Vec3>, QueryError> {
+        use File::*;
+        match self {
+            Obj(objs) => objs.query_mesh_positions(query),
+            Gltf(gltf) => gltf.query_mesh_positions(query),
+        }
+    }
+
+    fn inspect(&self) -> ModelInfo {
+        use File::*;
+        match self {
+            Obj(objs) => objs.inspect(),
+            Gltf(gltf) => gltf.inspect(),
+        }
+    }
 }
@@ -4,16 +4,17 @@ mod keyframes;
 mod interpolate;
 mod scene_anim_query_cache;
 
+use std::rc::Rc;
 use std::sync::Arc;
 use std::path::Path;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, BTreeMap};
 
 use glium::{
     Texture2d,
     texture::{RawImage2d, TextureCreationError, ClientFormat},
 };
 
-use crate::math::Mat4;
+use crate::math::{Mat4, Vec3, Milliseconds};
 use crate::scene::{
     Scene,
     NodeTree,
@@ -24,18 +25,33 @@ use crate::scene::{
     Skin,
     Material,
     ImageId,
+    MeshId,
     TexImage,
     Texture,
     CameraType,
     LightType,
 };
-use crate::renderer::{Display, ShaderGeometry, JointMatrixTexture, Camera, Light};
-use crate::query3d::{GeometryQuery, GeometryFilter, AnimationQuery, CameraQuery, LightQuery};
+use crate::renderer::{Display, ShaderGeometry, GeometryBuffers, JointMatrixTexture, Camera, Light};
+use crate::query3d::{
+    GeometryQuery,
+    GeometryFilter,
+    AnimationQuery,
+    RootMotionLock,
+    NodeQuery,
+    CameraQuery,
+    LightQuery,
+    ModelInfo,
+    SceneInfo,
+    AnimationInfo,
+    CameraInfo,
+    LightInfo,
+    MaterialInfo,
+};
 
-use super::{QueryBackend, QueryError};
+use super::{QueryBackend, QueryError, FileError};
 
 use scenes::Scenes;
-use animation::AnimationSet;
+use animation::{AnimationSet, Animation, NodePose};
 use scene_anim_query_cache::SceneAnimQueryCache;
 
 /// Represents a single glTF file
@@ -45,6 +61,8 @@ pub struct GltfFile {
     scenes: Scenes,
     /// This is a mapping of Node ID to all the animations that act on that node
     animations: HashMap<NodeId, AnimationSet>,
+    /// Every material in the file, whether or not it is used
+    materials: Vec<Arc<Material>>,
 
     /// Cache the default joint matrix texture so we don't upload it over and over again
     default_joint_matrix_texture: Option<Arc<JointMatrixTexture>>,
@@ -56,17 +74,19 @@ pub struct GltfFile {
     scene_lights: HashMap<usize, Arc<Vec<Arc<Light>>>>,
     /// Cache each light by scene index and name
     scene_named_lights: HashMap<(usize, String), Arc<Vec<Arc<Light>>>>,
-    /// Cache the first camera in the scene
-    scene_first_camera: Option<Arc<Camera>>,
+    /// Cache the first camera in each scene, referenced by scene index
+    scene_first_camera: HashMap<usize, Arc<Camera>>,
     /// Cache each camera by scene index and name
     scene_named_cameras: HashMap<(usize, String), Arc<Camera>>,
+    /// Cache of the vertex data of each mesh primitive, referenced by mesh ID and primitive index
+    geometry_buffers: HashMap<(MeshId, usize), Rc<GeometryBuffers>>,
     /// Cache of each image by image ID
     images: HashMap<ImageId, Arc<Texture2d>>,
 }
 
 impl GltfFile {
     /// Opens a glTF file
-    pub fn open(path: &Path) -> Result<Self, gltf::Error> {
+    pub fn open(path: &Path) -> Result<Self, FileError> {
         let (document, buffers, images) = gltf::import(path)?;
 
         let images: Vec<_> = images.into_iter().enumerate()
@@ -76,12 +96,12 @@ impl GltfFile {
             .map(|tex| Arc::new(Texture::from_gltf(tex, &images)))
             .collect();
         let materials: Vec<_> = document.materials()
-            .map(|mat| Arc::new(Material::from_gltf(mat, &textures)))
-            .collect();
+            .map(|mat| Material::from_gltf(mat, &textures).map(Arc::new))
+            .collect::<Result<_, _>>()?;
 
         let meshes: Vec<_> = document.meshes()
-            .map(|mesh| Arc::new(Mesh::from_gltf(mesh, &materials, &buffers)))
-            .collect();
+            .map(|mesh| Mesh::from_gltf(mesh, &materials, &buffers).map(Arc::new))
+            .collect::<Result<_, _>>()?;
 
         let skins: Vec<_> = document.skins()
             .map(|skin| Arc::new(Skin::from_gltf(skin, &buffers)))
@@ -95,35 +115,40 @@ impl GltfFile {
             lights.map(|light| Arc::new(LightType::from(light))).collect()
         }).unwrap_or_default();
 
-        let nodes = NodeTree::from_ordered_nodes(document.nodes().map(|node| {
+        let nodes: Vec<_> = document.nodes().map(|node| {
             let children = node.children().map(|node| NodeId::from_gltf(&node)).collect();
-            let node = Node::from_gltf(node, &meshes, &skins, &cameras, &lights);
-            (node, children)
-        }));
+            let node = Node::from_gltf(node, &meshes, &skins, &cameras, &lights)?;
+            Ok((node, children))
+        }).collect::<Result<_, FileError>>()?;
+        let nodes = NodeTree::from_ordered_nodes(nodes.into_iter());
 
         let scenes: Vec<_> = document.scenes()
             .map(|scene| Arc::new(Scene::from_gltf(scene)))
             .collect();
-        assert!(!scenes.is_empty(), "glTF file must have at least one scene");
+        if scenes.is_empty() {
+            return Err(FileError::NoScenes);
+        }
 
         // Get the default scene, or just use the first scene if no default is provided
         let default_scene = document.default_scene().map(|scene| scene.index()).unwrap_or(0);
         let scenes = Scenes::new(scenes, default_scene);
 
-        let animations = animation::from_animations(document.animations(), &buffers);
+        let animations = animation::from_animations(document.animations(), &buffers)?;
 
         Ok(Self {
             nodes,
             scenes,
             animations,
+            materials,
 
             default_joint_matrix_texture: None,
             scene_shader_geometry: HashMap::new(),
             scene_anim_shader_geometry: SceneAnimQueryCache::default(),
             scene_lights: HashMap::new(),
             scene_named_lights: HashMap::new(),
-            scene_first_camera: None,
+            scene_first_camera: HashMap::new(),
             scene_named_cameras: HashMap::new(),
+            geometry_buffers: HashMap::new(),
             images: HashMap::new(),
         })
     }
@@ -132,53 +157,184 @@ impl GltfFile {
 /// Returns a new node tree with the animations specified by the query applied to each matching
 /// node. Returns an error if the query did not match any of the nodes or if a single node
 /// was matched by multiple animations (ambiguous).
+///
+/// Any layers in the query are blended on top of the base animation, in order. The root motion
+/// lock is applied last, to the nodes it selects in the given scene.
 fn apply_animation_query(
     anim_query: &AnimationQuery,
     nodes: &NodeTree,
+    scene: &Scene,
     animations: &HashMap<NodeId, AnimationSet>,
 ) -> Result<NodeTree, QueryError> {
+    let AnimationQuery {name, position, layers, root_motion} = anim_query;
+
+    // The nodes that each layer is allowed to affect, or None if it can affect every node
+    let masks = layers.iter()
+        .map(|layer| layer.mask.as_deref().map(|mask| subtree_nodes(nodes, mask)).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+    let locked_nodes = root_motion.as_ref()
+        .map(|lock| locked_nodes(nodes, scene, lock))
+        .transpose()?;
+    let lock = |node: &Node, transform: Mat4| match (root_motion, &locked_nodes) {
+        (Some(lock), Some(locked)) if locked.contains(&node.id) => {
+            lock_translation(transform, &node.transform, lock)
+        },
+        _ => transform,
+    };
+
     // Set to true if at least one animation was found and applied on any node
     // If none are applied, the animation name was probably mispelled or something
     let mut animation_found = false;
+    let mut layers_found = vec![false; layers.len()];
+
+    let nodes = nodes.try_with_replacements(|node| -> Result<_, QueryError> {
+        // If the node has a list of animations, look for the animations that match the query
+        let anim_set = match animations.get(&node.id) {
+            Some(anim_set) => anim_set,
+            None => return Ok(None),
+        };
+
+        // anim is the animation that will modify the transformation matrix of the current node
+        let anim = find_animation(anim_set, name.as_deref())?;
+        animation_found |= anim.is_some();
+
+        if layers.is_empty() {
+            // Create and set the new transformation matrix of the current node
+            return Ok(anim.map(|anim| {
+                node.with_transform(lock(node, anim.apply_at(&node.transform, position)))
+            }));
+        }
 
-    let nodes = nodes.try_with_replacements(|node| {
-        match animations.get(&node.id) {
-            // If the node has a list of animations, look for the animations that match the AnimationQuery name
-            Some(anim_set) => {
-                // anim is the animation that will modify the transformation matrix of the current node
-                let mut anims = anim_set.filter(anim_query.name.as_deref());
-
-                // Return if no animation matches the name in the animation query
-                let anim = match anims.next() {
-                    Some(anim) => anim,
-                    None => return Ok(None),
-                };
-
-                // Return if multiple animations match the query
-                if anims.next().is_some() {
-                    return Err(QueryError::AmbiguousAnimation);
-                }
-
-                animation_found = true;
+        let mut pose = NodePose::from_transform(&node.transform);
+        let mut changed = false;
+        if let Some(anim) = anim {
+            pose = anim.sample(pose, position);
+            changed = true;
+        }
 
-                // Create and set the new transformation matrix of the current node
-                let new_transform = anim.apply_at(&node.transform, &anim_query.position);
-                Ok(Some(node.with_transform(new_transform)))
-            },
+        for ((layer, mask), found) in layers.iter().zip(&masks).zip(&mut layers_found) {
+            if mask.as_ref().map(|mask| !mask.contains(&node.id)).unwrap_or(false) {
+                continue;
+            }
 
-            None => Ok(None),
+            if let Some(anim) = find_animation(anim_set, Some(&layer.name))? {
+                pose = pose.blend(anim.sample(pose, &layer.position), layer.weight);
+                changed = true;
+                *found = true;
+            }
         }
+
+        Ok(if changed { Some(node.with_transform(lock(node, pose.to_transform()))) } else { None })
     })?;
 
     if !animation_found {
-        match &anim_query.name {
-            Some(name) => Err(QueryError::UnknownAnimation {name: name.to_string()}),
-            None => Err(QueryError::NoAnimationFound),
-        }
+        return Err(match name {
+            Some(name) => QueryError::UnknownAnimation {name: name.to_string()},
+            None => QueryError::NoAnimationFound,
+        });
+    }
+    if let Some((layer, _)) = layers.iter().zip(layers_found).find(|&(_, found)| !found) {
+        return Err(QueryError::UnknownAnimation {name: layer.name.clone()});
+    }
+
+    Ok(nodes)
+}
+
+/// Returns the animation in the set with the given name (or the only animation if `name` is None)
+///
+/// Returns an error if multiple animations match.
+fn find_animation<'a>(
+    anim_set: &'a AnimationSet,
+    name: Option<&'a str>,
+) -> Result<Option<&'a Animation>, QueryError> {
+    let mut anims = anim_set.filter(name);
+    let anim = anims.next();
+
+    // Return an error if multiple animations match the query
+    if anims.next().is_some() {
+        return Err(QueryError::AmbiguousAnimation);
+    }
+
+    Ok(anim)
+}
+
+/// Returns the IDs of the nodes in the given scene locked by the given root motion lock
+fn locked_nodes(
+    nodes: &NodeTree,
+    scene: &Scene,
+    lock: &RootMotionLock,
+) -> Result<HashSet<NodeId>, QueryError> {
+    match &lock.node {
+        Some(name) => {
+            let locked: HashSet<_> = scene_nodes(nodes, scene)
+                .map(|(node, _)| node)
+                .filter(|node| node.name.as_deref() == Some(name))
+                .map(|node| node.id)
+                .collect();
+
+            if locked.is_empty() {
+                return Err(QueryError::UnknownNode {name: name.clone()});
+            }
+
+            Ok(locked)
+        },
+
+        None => Ok(scene.roots.iter().copied().collect()),
+    }
+}
+
+/// Replaces the locked components of the translation of an animated transform with those of the
+/// node's original transform
+fn lock_translation(transform: Mat4, original: &Mat4, lock: &RootMotionLock) -> Mat4 {
+    let offset = translation_offset(&transform, original, lock);
+
+    let mut transform = transform;
+    transform.cols.w.x -= offset.x;
+    transform.cols.w.y -= offset.y;
+    transform.cols.w.z -= offset.z;
+    transform
+}
+
+/// Returns the single offset shared by every locked node that was moved, or zero if no node was
+/// moved
+///
+/// Only one offset can be reported for a frame, so it is an error for the locked nodes to have
+/// been moved by different amounts.
+fn combined_offset(offsets: impl IntoIterator<Item=Vec3>) -> Result<Vec3, QueryError> {
+    let mut offsets = offsets.into_iter().filter(|&offset| offset != Vec3::zero());
+    let offset = offsets.next().unwrap_or_else(Vec3::zero);
+    if offsets.any(|other| other != offset) {
+        return Err(QueryError::AmbiguousRootMotion);
+    }
+
+    Ok(offset)
+}
+
+/// Returns the translation that would be removed from the animated transform by the given lock
+fn translation_offset(transform: &Mat4, original: &Mat4, lock: &RootMotionLock) -> Vec3 {
+    let &RootMotionLock {node: _, x, y, z} = lock;
+
+    let animated = transform.cols.w;
+    let original = original.cols.w;
+    Vec3 {
+        x: if x { animated.x - original.x } else { 0.0 },
+        y: if y { animated.y - original.y } else { 0.0 },
+        z: if z { animated.z - original.z } else { 0.0 },
+    }
+}
+
+/// Returns the IDs of every node in the subtrees of the nodes with the given name
+fn subtree_nodes(nodes: &NodeTree, name: &str) -> Result<HashSet<NodeId>, QueryError> {
+    let subtree: HashSet<_> = nodes.iter()
+        .filter(|node| node.name.as_deref() == Some(name))
+        .flat_map(|root| nodes.traverse(root.id).map(|(_, node)| node.id))
+        .collect();
 
-    } else {
-        Ok(nodes)
+    if subtree.is_empty() {
+        return Err(QueryError::UnknownNode {name: name.to_string()});
     }
+
+    Ok(subtree)
 }
 
 /// Returns the joint matrix texture for the given skin and model matrix
@@ -255,11 +411,15 @@ fn image_lookup(
 /// Given nodes and their model/world transforms, uploads each node's geometry
 ///
 /// This can't be a method because we need to keep the &mut self borrow split
+// The geometry holds GPU resources that belong to the render context of the current thread. It is
+// only shared between the frames drawn on this thread, so it never needs to be `Send` or `Sync`.
+#[allow(clippy::arc_with_non_send_sync)]
 fn upload_geometry<'a>(
     nodes: impl Iterator<Item=(&'a Node, Mat4)>,
     node_world_transforms: &NodeWorldTransforms,
     display: &Display,
     default_joint_matrix_texture: &mut Option<Arc<JointMatrixTexture>>,
+    geometry_buffers: &mut HashMap<(MeshId, usize), Rc<GeometryBuffers>>,
     images: &mut HashMap<ImageId, Arc<Texture2d>>,
 ) -> Result<Arc<Vec<Arc<ShaderGeometry>>>, QueryError> {
     let mut scene_geo = Vec::new();
@@ -274,16 +434,26 @@ fn upload_geometry<'a>(
                 default_joint_matrix_texture,
             )?;
 
-            for geo in &mesh.geometry {
-                let geo = ShaderGeometry::new(
-                    display,
-                    geo,
-                    &joint_matrices_tex,
-                    model_transform,
-                    |img| image_lookup(images, display, img),
-                )?;
+            for (i, geo) in mesh.geometry.iter().enumerate() {
+                // The vertex data never changes, so it is only uploaded the first time it is used
+                let buffers = match geometry_buffers.get(&(mesh.id, i)) {
+                    Some(buffers) => buffers.clone(),
+                    None => {
+                        let buffers = Rc::new(GeometryBuffers::new(
+                            display,
+                            geo,
+                            |img| image_lookup(images, display, img),
+                        )?);
+                        geometry_buffers.insert((mesh.id, i), buffers.clone());
+                        buffers
+                    },
+                };
 
-                scene_geo.push(Arc::new(geo));
+                scene_geo.push(Arc::new(ShaderGeometry {
+                    buffers,
+                    joint_matrices: joint_matrices_tex.clone(),
+                    model_transform,
+                }));
             }
         }
     }
@@ -296,6 +466,88 @@ fn upload_geometry<'a>(
     Ok(scene_geo)
 }
 
+/// Iterates over the nodes in the given scene and their model/world transforms
+fn scene_nodes<'a>(nodes: &'a NodeTree, scene: &'a Scene) -> impl Iterator<Item=(&'a Node, Mat4)> {
+    scene.roots.iter()
+        .flat_map(move |&root| nodes.traverse(root))
+        .map(|(parent_trans, node)| (node, parent_trans * node.transform))
+}
+
+/// Returns the first camera in the given scene
+fn first_camera(nodes: &NodeTree, scene: &Scene) -> Option<Arc<Camera>> {
+    scene.roots.iter()
+        .flat_map(|&root| nodes.traverse(root))
+        .find_map(|(parent_trans, node)| {
+            let world_transform = parent_trans * node.transform;
+
+            node.camera().map(|cam| Arc::new(Camera {
+                view: world_transform.inverted(),
+                projection: cam.to_projection(),
+            }))
+        })
+}
+
+/// Returns the camera with the given name (or in the node with the given name) in the given scene
+fn named_camera(nodes: &NodeTree, scene: &Scene, name: &str) -> Option<Arc<Camera>> {
+    // This code assumes that camera names are unique
+    scene.roots.iter()
+        .flat_map(|&root| nodes.traverse(root))
+        .find_map(|(parent_trans, node)| {
+            let world_transform = parent_trans * node.transform;
+
+            match node.camera() {
+                Some(cam) if node.name.as_deref() == Some(name) || cam.name() == Some(name) => {
+                    Some(Arc::new(Camera {
+                        view: world_transform.inverted(),
+                        projection: cam.to_projection(),
+                    }))
+                },
+
+                Some(_) |
+                None => None,
+            }
+        })
+}
+
+/// Returns all of the lights in the given scene
+fn scene_lights(nodes: &NodeTree, scene: &Scene) -> Vec<Arc<Light>> {
+    scene.roots.iter()
+        .flat_map(|&root| nodes.traverse(root))
+        .filter_map(|(parent_trans, node)| {
+            let world_transform = parent_trans * node.transform;
+            node.light().map(|light| Arc::new(Light {data: light.clone(), world_transform}))
+        })
+        .collect()
+}
+
+/// Returns the lights with the given name (or in the nodes with the given name) in the given scene
+fn named_lights(nodes: &NodeTree, scene: &Scene, name: &str) -> Vec<Arc<Light>> {
+    scene.roots.iter()
+        .flat_map(|&root| nodes.traverse(root))
+        .filter_map(|(parent_trans, node)| {
+            let world_transform = parent_trans * node.transform;
+
+            match node.light() {
+                Some(light) if node.name.as_deref() == Some(name) || light.name() == Some(name) => {
+                    Some(Arc::new(Light {data: light.clone(), world_transform}))
+                },
+
+                Some(_) |
+                None => None,
+            }
+        })
+        .collect()
+}
+
+/// Returns an error if no lights were found
+fn non_empty_lights(lights: Vec<Arc<Light>>) -> Result<Arc<Vec<Arc<Light>>>, QueryError> {
+    if lights.is_empty() {
+        return Err(QueryError::NoLightsFound);
+    }
+
+    Ok(Arc::new(lights))
+}
+
 impl QueryBackend for GltfFile {
     fn query_geometry(&mut self, query: &GeometryQuery, display: &Display) -> Result<Arc<Vec<Arc<ShaderGeometry>>>, QueryError> {
         let GeometryQuery {models, animation} = query;
@@ -313,18 +565,19 @@ impl QueryBackend for GltfFile {
                         Some(scene_geo) => Ok(scene_geo.clone()),
 
                         None => {
-                            let nodes = apply_animation_query(anim_query, nodes, animations)?;
-
                             let scene = &scenes[scene_index];
+                            let nodes = apply_animation_query(anim_query, nodes, scene, animations)?;
+
                             let node_world_transforms = nodes.world_transforms(&scene.roots);
 
                             // Upload the geometry for the entire scene since that's what the
                             // filter requested
                             let scene_geo = upload_geometry(
-                                node_world_transforms.iter(&nodes),
+                                scene_nodes(&nodes, scene),
                                 &node_world_transforms,
                                 display,
                                 default_joint_matrix_texture,
+                                &mut self.geometry_buffers,
                                 &mut self.images,
                             )?;
                             self.scene_anim_shader_geometry.insert(scene_index, anim_query, scene_geo.clone());
@@ -349,10 +602,11 @@ impl QueryBackend for GltfFile {
                             // Upload the geometry for the entire scene since that's what the
                             // filter requested
                             let scene_geo = upload_geometry(
-                                node_world_transforms.iter(nodes),
+                                scene_nodes(nodes, scene),
                                 &node_world_transforms,
                                 display,
                                 default_joint_matrix_texture,
+                                &mut self.geometry_buffers,
                                 &mut self.images,
                             )?;
                             self.scene_shader_geometry.insert(scene_index, scene_geo.clone());
@@ -365,35 +619,51 @@ impl QueryBackend for GltfFile {
         }
     }
 
+    fn check_geometry(&self, query: &GeometryQuery) -> Result<(), QueryError> {
+        let GeometryQuery {models, animation} = query;
+
+        use GeometryFilter::*;
+        let scene_index = match models {
+            Scene {name} => self.scenes.query(name.as_deref())?,
+        };
+
+        if let Some(anim_query) = animation {
+            // Animations do not change which nodes have geometry, so the result can be discarded
+            apply_animation_query(anim_query, &self.nodes, &self.scenes[scene_index], &self.animations)?;
+        }
+
+        // upload_geometry goes through every node in the scene, so that's what we check here too
+        let has_geometry = scene_nodes(&self.nodes, &self.scenes[scene_index])
+            .any(|(node, _)| node.mesh().map(|(mesh, _)| !mesh.geometry.is_empty()).unwrap_or(false));
+        if !has_geometry {
+            return Err(QueryError::NoGeometryFound);
+        }
+
+        Ok(())
+    }
+
     fn query_camera(&mut self, query: &CameraQuery) -> Result<Arc<Camera>, QueryError> {
         use CameraQuery::*;
         match query {
-            FirstInScene {name} => {
+            FirstInScene {name, animation} => {
                 let scene_index = self.scenes.query(name.as_deref())?;
 
-                match &self.scene_first_camera {
+                // Animated cameras are not cached since every frame is usually at a different
+                // position in the animation
+                if let Some(anim_query) = animation {
+                    let scene = &self.scenes[scene_index];
+                    let nodes = apply_animation_query(anim_query, &self.nodes, scene, &self.animations)?;
+                    return first_camera(&nodes, scene).ok_or(QueryError::NoCameraFound);
+                }
+
+                match self.scene_first_camera.get(&scene_index) {
                     Some(cam) => Ok(cam.clone()),
 
                     None => {
                         let scene = &self.scenes[scene_index];
-
-                        let mut nodes = scene.roots.iter().flat_map(|&root| self.nodes.traverse(root));
-                        let scene_first_camera = nodes.find_map(|(parent_trans, node)| {
-                            let world_transform = parent_trans * node.transform;
-
-                            match node.camera() {
-                                Some(cam) => Some(Arc::new(Camera {
-                                    view: world_transform.inverted(),
-                                    projection: cam.to_projection(),
-                                })),
-
-                                None => None,
-                            }
-                        });
-
-                        match scene_first_camera {
+                        match first_camera(&self.nodes, scene) {
                             Some(cam) => {
-                                self.scene_first_camera = Some(cam.clone());
+                                self.scene_first_camera.insert(scene_index, cam.clone());
                                 Ok(cam)
                             },
 
@@ -403,35 +673,23 @@ impl QueryBackend for GltfFile {
                 }
             },
 
-            Named {name, scene} => {
+            Named {name, scene, animation} => {
                 let scene_index = self.scenes.query(scene.as_deref())?;
 
+                if let Some(anim_query) = animation {
+                    let scene = &self.scenes[scene_index];
+                    let nodes = apply_animation_query(anim_query, &self.nodes, scene, &self.animations)?;
+                    return named_camera(&nodes, scene, name)
+                        .ok_or_else(|| QueryError::UnknownCamera {name: name.to_string()});
+                }
+
                 let cam_key = (scene_index, name.clone());
                 match self.scene_named_cameras.get(&cam_key) {
                     Some(cam) => Ok(cam.clone()),
 
                     None => {
                         let scene = &self.scenes[scene_index];
-
-                        let mut nodes = scene.roots.iter().flat_map(|&root| self.nodes.traverse(root));
-                        // This code assumes that camera names are unique
-                        let found_camera = nodes.find_map(|(parent_trans, node)| {
-                            let world_transform = parent_trans * node.transform;
-
-                            match node.camera() {
-                                Some(cam) if node.name.as_ref() == Some(name) || cam.name() == Some(name) => {
-                                    Some(Arc::new(Camera {
-                                        view: world_transform.inverted(),
-                                        projection: cam.to_projection(),
-                                    }))
-                                },
-
-                                Some(_) |
-                                None => None,
-                            }
-                        });
-
-                        match found_camera {
+                        match named_camera(&self.nodes, scene, name) {
                             Some(cam) => {
                                 self.scene_named_cameras.insert(cam_key, cam.clone());
                                 Ok(cam)
@@ -448,70 +706,300 @@ impl QueryBackend for GltfFile {
     fn query_lights(&mut self, query: &LightQuery) -> Result<Arc<Vec<Arc<Light>>>, QueryError> {
         use LightQuery::*;
         match query {
-            Scene {name} => {
+            Scene {name, animation} => {
                 let scene_index = self.scenes.query(name.as_deref())?;
 
+                // Animated lights are not cached since every frame is usually at a different
+                // position in the animation
+                if let Some(anim_query) = animation {
+                    let scene = &self.scenes[scene_index];
+                    let nodes = apply_animation_query(anim_query, &self.nodes, scene, &self.animations)?;
+                    return non_empty_lights(scene_lights(&nodes, scene));
+                }
+
                 match self.scene_lights.get(&scene_index) {
                     Some(scene_lights) => Ok(scene_lights.clone()),
 
                     None => {
                         let scene = &self.scenes[scene_index];
-
-                        let mut scene_lights = Vec::new();
-                        for (parent_trans, node) in scene.roots.iter().flat_map(|&root| self.nodes.traverse(root)) {
-                            let world_transform = parent_trans * node.transform;
-
-                            if let Some(light) = node.light() {
-                                let light = Light {data: light.clone(), world_transform};
-                                scene_lights.push(Arc::new(light));
-                            }
-                        }
-
-                        if scene_lights.is_empty() {
-                            return Err(QueryError::NoLightsFound);
-                        }
-
-                        let scene_lights = Arc::new(scene_lights);
-                        self.scene_lights.insert(scene_index, scene_lights.clone());
-                        Ok(scene_lights)
+                        let lights = non_empty_lights(scene_lights(&self.nodes, scene))?;
+                        self.scene_lights.insert(scene_index, lights.clone());
+                        Ok(lights)
                     },
                 }
             },
 
-            Named {name, scene} => {
+            Named {name, scene, animation} => {
                 let scene_index = self.scenes.query(scene.as_deref())?;
 
+                if let Some(anim_query) = animation {
+                    let scene = &self.scenes[scene_index];
+                    let nodes = apply_animation_query(anim_query, &self.nodes, scene, &self.animations)?;
+                    return non_empty_lights(named_lights(&nodes, scene, name));
+                }
+
                 let light_key = (scene_index, name.clone());
                 match self.scene_named_lights.get(&light_key) {
                     Some(scene_lights) => Ok(scene_lights.clone()),
 
                     None => {
                         let scene = &self.scenes[scene_index];
+                        let lights = non_empty_lights(named_lights(&self.nodes, scene, name))?;
+                        self.scene_named_lights.insert(light_key, lights.clone());
+                        Ok(lights)
+                    },
+                }
+            },
+        }
+    }
 
-                        let nodes = scene.roots.iter().flat_map(|&root| self.nodes.traverse(root));
-                        let scene_lights: Vec<_> = nodes.filter_map(|(parent_trans, node)| {
-                            let world_transform = parent_trans * node.transform;
+    fn query_scene_names(&self) -> Result<Vec<String>, QueryError> {
+        self.scenes.names()
+    }
 
-                            match node.light() {
-                                Some(light) if node.name.as_ref() == Some(name) || light.name() == Some(name) => {
-                                    Some(Arc::new(Light {data: light.clone(), world_transform}))
-                                },
+    fn query_animation_end_time(&self, name: Option<&str>) -> Result<Milliseconds, QueryError> {
+        let mut end_time = None;
+        for anim_set in self.animations.values() {
+            if let Some(anim) = find_animation(anim_set, name)? {
+                let anim_end = anim.end_time();
+                end_time = match end_time {
+                    Some(end) if end >= anim_end => Some(end),
+                    _ => Some(anim_end),
+                };
+            }
+        }
 
-                                Some(_) |
-                                None => None,
-                            }
-                        }).collect();
+        end_time.ok_or_else(|| match name {
+            Some(name) => QueryError::UnknownAnimation {name: name.to_string()},
+            None => QueryError::NoAnimationFound,
+        })
+    }
 
-                        if scene_lights.is_empty() {
-                            return Err(QueryError::NoLightsFound);
-                        }
+    fn query_root_motion(&self, query: &AnimationQuery, scene: Option<&str>) -> Result<Vec3, QueryError> {
+        let lock = match &query.root_motion {
+            Some(lock) => lock,
+            None => return Ok(Vec3::zero()),
+        };
+        let scene = &self.scenes[self.scenes.query(scene)?];
+
+        // Find the translation the animation would have had without the lock
+        let unlocked_query = AnimationQuery {root_motion: None, ..query.clone()};
+        let animated = apply_animation_query(&unlocked_query, &self.nodes, scene, &self.animations)?;
+        let locked = locked_nodes(&self.nodes, scene, lock)?;
+
+        let offsets = self.nodes.iter()
+            .filter(|node| locked.contains(&node.id))
+            .map(|node| translation_offset(&animated.get(node.id).transform, &node.transform, lock));
+        combined_offset(offsets)
+    }
 
-                        let scene_lights = Arc::new(scene_lights);
-                        self.scene_named_lights.insert(light_key, scene_lights.clone());
-                        Ok(scene_lights)
-                    },
-                }
-            },
+    fn query_node_transform(&self, query: &NodeQuery) -> Result<Mat4, QueryError> {
+        let NodeQuery {name, scene, animation} = query;
+
+        let scene_index = self.scenes.query(scene.as_deref())?;
+        let scene = &self.scenes[scene_index];
+
+        let animated_nodes = animation.as_ref()
+            .map(|anim_query| apply_animation_query(anim_query, &self.nodes, scene, &self.animations))
+            .transpose()?;
+        let nodes = animated_nodes.as_ref().unwrap_or(&self.nodes);
+
+        scene.roots.iter()
+            .flat_map(|&root| nodes.traverse(root))
+            .find(|(_, node)| node.name.as_ref() == Some(name))
+            .map(|(parent_trans, node)| parent_trans * node.transform)
+            .ok_or_else(|| QueryError::UnknownNode {name: name.clone()})
+    }
+
+    fn query_mesh_positions(&self, query: &NodeQuery) -> Result<Vec<Vec3>, QueryError> {
+        let NodeQuery {name, scene, animation} = query;
+
+        let scene_index = self.scenes.query(scene.as_deref())?;
+        let scene = &self.scenes[scene_index];
+
+        let animated_nodes = animation.as_ref()
+            .map(|anim_query| apply_animation_query(anim_query, &self.nodes, scene, &self.animations))
+            .transpose()?;
+        let nodes = animated_nodes.as_ref().unwrap_or(&self.nodes);
+
+        let node = scene.roots.iter()
+            .flat_map(|&root| nodes.traverse(root))
+            .map(|(_, node)| node)
+            .find(|node| node.name.as_ref() == Some(name))
+            .ok_or_else(|| QueryError::UnknownNode {name: name.clone()})?;
+        let (mesh, skin) = node.mesh().ok_or_else(|| QueryError::NodeHasNoMesh {name: name.clone()})?;
+
+        let node_world_transforms = nodes.world_transforms(&scene.roots);
+        let model_transform = node_world_transforms.get(node.id);
+        let joint_matrices: Vec<_> = skin
+            .map(|skin| skin.joint_matrices(model_transform, &node_world_transforms).collect())
+            .unwrap_or_default();
+        let joint_matrices = &joint_matrices;
+
+        mesh.geometry.iter().flat_map(|geo| {
+            let joints = geo.joint_influences.as_ref().zip(geo.joint_weights.as_ref())
+                .filter(|_| !joint_matrices.is_empty());
+
+            geo.positions.iter().enumerate().map(move |(i, &pos)| {
+                // This is the same skinning that the vertex shaders do
+                let pos = match joints {
+                    Some((influences, weights)) => influences[i].iter()
+                        .zip(weights[i].into_array().iter())
+                        .map(|(&joint, &weight)| {
+                            let joint = joint as usize;
+                            joint_matrices.get(joint)
+                                .map(|joint_matrix| joint_matrix.mul_point(pos) * weight)
+                                .ok_or_else(|| QueryError::InvalidJointIndex {name: name.clone(), joint})
+                        })
+                        .sum::<Result<Vec3, QueryError>>()?,
+                    None => pos,
+                };
+
+                Ok(model_transform.mul_point(pos))
+            })
+        }).collect()
+    }
+
+    fn inspect(&self) -> ModelInfo {
+        let Self {nodes, scenes, animations, materials, ..} = self;
+
+        // Animations are stored per node, so the same animation may be found many times
+        let mut anim_end_times = BTreeMap::new();
+        for anim in animations.values().flat_map(|anim_set| anim_set.filter(None)) {
+            let end_time = anim_end_times.entry(anim.name.clone()).or_insert_with(|| anim.end_time());
+            if anim.end_time() > *end_time {
+                *end_time = anim.end_time();
+            }
+        }
+
+        ModelInfo {
+            scenes: scenes.iter()
+                .map(|(scene, default)| SceneInfo::from_scene(scene, default, nodes))
+                .collect(),
+            animations: anim_end_times.into_iter()
+                .map(|(name, duration)| AnimationInfo {name, duration})
+                .collect(),
+            cameras: nodes.iter()
+                .filter_map(|node| node.camera().map(|cam| CameraInfo::from_camera(cam, node)))
+                .collect(),
+            lights: nodes.iter()
+                .filter_map(|node| node.light().map(|light| LightInfo::from_light(light, node)))
+                .collect(),
+            materials: materials.iter().map(|mat| MaterialInfo::from(&**mat)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::math::Vec4;
+    use crate::query3d::{AnimationLayer, AnimationPosition};
+
+    fn open_bigboi() -> GltfFile {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("samples/bigboi/bigboi.gltf");
+        GltfFile::open(&path).unwrap()
+    }
+
+    fn lock(node: Option<&str>, x: bool, y: bool, z: bool) -> RootMotionLock {
+        RootMotionLock {node: node.map(String::from), x, y, z}
+    }
+
+    fn walk_at(time: f32) -> AnimationQuery {
+        AnimationQuery {
+            name: Some("walk".to_string()),
+            position: AnimationPosition::Time(Milliseconds::from_msec(time)),
+            layers: Vec::new(),
+            root_motion: None,
+        }
+    }
+
+    #[test]
+    fn masked_layers_only_affect_their_subtree() {
+        let GltfFile {nodes, scenes, animations, ..} = open_bigboi();
+        let scene = &scenes[scenes.query(None).unwrap()];
+
+        let base = apply_animation_query(&walk_at(0.0), &nodes, scene, &animations).unwrap();
+        let layer = apply_animation_query(&walk_at(500.0), &nodes, scene, &animations).unwrap();
+
+        let mut query = walk_at(0.0);
+        query.layers.push(AnimationLayer {
+            name: "walk".to_string(),
+            position: AnimationPosition::Time(Milliseconds::from_msec(500.0)),
+            weight: 1.0,
+            mask: Some("upper_arm.L".to_string()),
+        });
+        let blended = apply_animation_query(&query, &nodes, scene, &animations).unwrap();
+
+        let mask = subtree_nodes(&nodes, "upper_arm.L").unwrap();
+        assert_eq!(mask.len(), 2);
+        assert!(mask.iter().any(|&id| base.get(id).transform != layer.get(id).transform));
+        for node in blended.iter() {
+            let expected = if mask.contains(&node.id) { &layer } else { &base };
+            let expected = expected.get(node.id).transform;
+            let actual = node.transform;
+            let close = expected.into_row_array().iter().zip(&actual.into_row_array())
+                .all(|(a, b)| (a - b).abs() < 1e-4);
+            assert!(close, "node {:?}: expected {:?}, got {:?}", node.name, expected, actual);
+        }
+    }
+
+    #[test]
+    fn locked_translation_components() {
+        let original = Mat4::translation_3d(Vec3::new(1.0, 2.0, 3.0));
+        let animated = Mat4::translation_3d(Vec3::new(4.0, 6.0, 8.0)) * Mat4::rotation_z(0.5);
+
+        let ground = lock(None, true, false, true);
+        assert_eq!(translation_offset(&animated, &original, &ground), Vec3::new(3.0, 0.0, 5.0));
+        let locked = lock_translation(animated, &original, &ground);
+        assert_eq!(locked.cols.w, Vec4::new(1.0, 6.0, 3.0, 1.0));
+        // Only the translation is locked
+        assert_eq!(locked.cols.x, animated.cols.x);
+        assert_eq!(locked.cols.y, animated.cols.y);
+        assert_eq!(locked.cols.z, animated.cols.z);
+
+        let all = lock(None, true, true, true);
+        assert_eq!(translation_offset(&animated, &original, &all), Vec3::new(3.0, 4.0, 5.0));
+        assert_eq!(lock_translation(animated, &original, &all).cols.w, original.cols.w);
+
+        let none = lock(None, false, false, false);
+        assert_eq!(translation_offset(&animated, &original, &none), Vec3::zero());
+        assert_eq!(lock_translation(animated, &original, &none), animated);
+    }
+
+    #[test]
+    fn offsets_of_multiple_locked_nodes() {
+        let moved = Vec3::new(1.0, 0.0, 2.0);
+        assert_eq!(combined_offset(vec![]).unwrap(), Vec3::zero());
+        assert_eq!(combined_offset(vec![Vec3::zero(), moved, Vec3::zero()]).unwrap(), moved);
+        // Nodes that move together have the same offset
+        assert_eq!(combined_offset(vec![moved, moved]).unwrap(), moved);
+        assert!(matches!(combined_offset(vec![moved, Vec3::unit_x()]), Err(QueryError::AmbiguousRootMotion)));
+    }
+
+    #[test]
+    fn locked_nodes_are_in_the_scene() {
+        let GltfFile {nodes, scenes, ..} = open_bigboi();
+        let scene = &scenes[scenes.query(None).unwrap()];
+        let names = |ids: HashSet<NodeId>| {
+            let mut names: Vec<_> = ids.into_iter().filter_map(|id| nodes.get(id).name.clone()).collect();
+            names.sort();
+            names
+        };
+
+        let roots = locked_nodes(&nodes, scene, &lock(None, true, false, true)).unwrap();
+        assert_eq!(roots, scene.roots.iter().copied().collect());
+        let roots = names(roots);
+        assert!(roots.contains(&"bigboi_skeleton".to_string()));
+        assert!(!roots.contains(&"root".to_string()));
+
+        let spine = locked_nodes(&nodes, scene, &lock(Some("spine"), true, false, true)).unwrap();
+        assert_eq!(names(spine), vec!["spine".to_string()]);
+
+        match locked_nodes(&nodes, scene, &lock(Some("tail"), true, false, true)) {
+            Err(QueryError::UnknownNode {name}) => assert_eq!(name, "tail"),
+            result => panic!("expected an unknown node, got {:?}", result),
         }
     }
 }
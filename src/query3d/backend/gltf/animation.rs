@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
-use crate::query3d::query::AnimationPosition;
-use crate::math::{Milliseconds, Vec3, Quaternion, Mat4, Mat3, Decompose};
+use crate::query3d::{AnimationPosition, FileError, ItemRef};
+use crate::math::{Milliseconds, Vec3, Quaternion, Mat4, Mat3, Decompose, Transforms};
 use crate::scene::NodeId;
 
 use super::keyframes::Keyframes;
-use super::interpolate::Interpolation;
+use super::interpolate::{Interpolation, Interpolate};
 
 #[derive(Debug, Default, Clone)]
 pub struct AnimationSet {
@@ -56,45 +56,70 @@ impl Animation {
 
     /// Sets the keyframes from the given glTF data.
     ///
-    /// Panics if this operation would overwrite any of the existing keyframes.
+    /// Returns an error if this operation would overwrite any of the existing keyframes.
     pub fn set_keyframes(
         &mut self,
         channel: gltf::animation::Channel,
         buffers: &[gltf::buffer::Data],
         interpolation: Interpolation,
-    ) {
+        context: &ChannelContext,
+    ) -> Result<(), FileError> {
         let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
-        let times = reader.read_inputs().expect("Animation detected with no sampler input values")
-            .map(|time| Milliseconds::from_sec(time));
+        let times: Vec<_> = reader.read_inputs()
+            .ok_or_else(|| context.missing_values("input"))?
+            .map(|time| Milliseconds::from_sec(time))
+            .collect();
+        if times.is_empty() {
+            return Err(context.missing_values("input"));
+        }
 
         use gltf::animation::util::ReadOutputs::*;
-        match reader.read_outputs().expect("Animation detected with no sampler output values") {
+        match reader.read_outputs().ok_or_else(|| context.missing_values("output"))? {
             Scales(scales) => {
-                assert!(self.scale.is_none(),
-                    "Did not expect animation with the same name to have multiple sets of scale keyframes");
+                if self.scale.is_some() {
+                    return Err(context.duplicate_keyframes("scale"));
+                }
 
                 let values = scales.map(Vec3::from);
-                self.scale = Some(Keyframes::new(times, values, interpolation));
+                self.scale = Some(non_empty_keyframes(times, values, interpolation, context)?);
             },
 
             Rotations(rotations) => {
-                assert!(self.rotation.is_none(),
-                    "Did not expect animation with the same name to have multiple sets of rotation keyframes");
+                if self.rotation.is_some() {
+                    return Err(context.duplicate_keyframes("rotation"));
+                }
 
                 let values = rotations.into_f32().map(|[x, y, z, w]| Quaternion::from_xyzw(x, y, z, w));
-                self.rotation = Some(Keyframes::new(times, values, interpolation));
+                self.rotation = Some(non_empty_keyframes(times, values, interpolation, context)?);
             },
 
             Translations(translations) => {
-                assert!(self.translation.is_none(),
-                    "Did not expect animation with the same name to have multiple sets of translation keyframes");
+                if self.translation.is_some() {
+                    return Err(context.duplicate_keyframes("translation"));
+                }
 
                 let values = translations.map(Vec3::from);
-                self.translation = Some(Keyframes::new(times, values, interpolation));
+                self.translation = Some(non_empty_keyframes(times, values, interpolation, context)?);
             },
 
-            MorphTargetWeights(_) => unimplemented!("Morph target animations are not supported yet"),
+            MorphTargetWeights(_) => return Err(FileError::UnsupportedMorphTargets {
+                animation: context.animation.clone(),
+                node: context.node.clone(),
+            }),
         };
+
+        Ok(())
+    }
+
+    /// Returns the time of the last keyframe across all of the channels of this animation
+    pub fn end_time(&self) -> Milliseconds {
+        let Self {name: _, scale, rotation, translation} = self;
+
+        // Every animation has at least one channel, so at least one of these must be present
+        let end_times = scale.as_ref().map(Keyframes::end_time).into_iter()
+            .chain(rotation.as_ref().map(Keyframes::end_time))
+            .chain(translation.as_ref().map(Keyframes::end_time));
+        end_times.fold(Milliseconds::default(), |end, time| if time > end { time } else { end })
     }
 
     /// Applies the animation to the given transform by finding the value of its components at the
@@ -117,22 +142,163 @@ impl Animation {
 
         Mat4::from(components)
     }
+
+    /// Returns the given pose with each of the components that this animation animates replaced
+    /// with their value at the given position
+    pub fn sample(&self, pose: NodePose, pos: &AnimationPosition) -> NodePose {
+        let NodePose {mut scale, mut rotation, mut translation} = pose;
+
+        if let Some(keyframes) = &self.scale {
+            scale = keyframes.value_at(pos);
+        }
+        if let Some(keyframes) = &self.rotation {
+            rotation = keyframes.value_at(pos);
+        }
+        if let Some(keyframes) = &self.translation {
+            translation = keyframes.value_at(pos);
+        }
+
+        NodePose {scale, rotation, translation}
+    }
+}
+
+/// Converts a rotation matrix into the equivalent quaternion
+fn rotation_to_quaternion(m: Mat3) -> Quaternion {
+    // See: http://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/
+    let (m00, m01, m02) = (m[(0, 0)], m[(0, 1)], m[(0, 2)]);
+    let (m10, m11, m12) = (m[(1, 0)], m[(1, 1)], m[(1, 2)]);
+    let (m20, m21, m22) = (m[(2, 0)], m[(2, 1)], m[(2, 2)]);
+
+    let trace = m00 + m11 + m22;
+    if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        Quaternion::from_xyzw((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, s / 4.0)
+    } else if m00 > m11 && m00 > m22 {
+        let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+        Quaternion::from_xyzw(s / 4.0, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+    } else if m11 > m22 {
+        let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+        Quaternion::from_xyzw((m01 + m10) / s, s / 4.0, (m12 + m21) / s, (m02 - m20) / s)
+    } else {
+        let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+        Quaternion::from_xyzw((m02 + m20) / s, (m12 + m21) / s, s / 4.0, (m10 - m01) / s)
+    }
+}
+
+/// The components of the local transform of a node, in a form that can be blended
+#[derive(Debug, Clone, Copy)]
+pub struct NodePose {
+    pub scale: Vec3,
+    pub rotation: Quaternion,
+    pub translation: Vec3,
+}
+
+impl NodePose {
+    pub fn from_transform(transform: &Mat4) -> Self {
+        let Transforms {scale, rotation, translation} = transform.decompose();
+
+        Self {
+            scale,
+            rotation: rotation_to_quaternion(rotation),
+            translation,
+        }
+    }
+
+    pub fn to_transform(self) -> Mat4 {
+        let Self {scale, rotation, translation} = self;
+
+        Mat4::from(Transforms {
+            scale,
+            rotation: Mat3::from(rotation),
+            translation,
+        })
+    }
+
+    /// Blends between this pose and the other pose. A weight of 0.0 returns this pose and a weight
+    /// of 1.0 returns the other pose.
+    pub fn blend(self, other: NodePose, weight: f32) -> Self {
+        let method = Interpolation::Linear;
+        Self {
+            scale: Vec3::interpolate(method, weight, &self.scale, &other.scale),
+            rotation: Quaternion::interpolate(method, weight, &self.rotation, &other.rotation),
+            translation: Vec3::interpolate(method, weight, &self.translation, &other.translation),
+        }
+    }
+}
+
+/// Creates keyframes from the given times and values, returning an error if there are no values
+/// or if any of the times are not finite
+fn non_empty_keyframes<T>(
+    times: Vec<Milliseconds>,
+    values: impl Iterator<Item=T>,
+    interpolation: Interpolation,
+    context: &ChannelContext,
+) -> Result<Keyframes<T>, FileError> {
+    if times.iter().any(|time| !time.to_msec().is_finite()) {
+        return Err(FileError::NonFiniteKeyframeTime {
+            animation: context.animation.clone(),
+            node: context.node.clone(),
+        });
+    }
+
+    let keyframes = Keyframes::new(times.into_iter(), values, interpolation);
+    if keyframes.frames.is_empty() {
+        return Err(context.missing_values("output"));
+    }
+
+    Ok(keyframes)
+}
+
+/// The animation and node that an animation channel belongs to, used for error messages
+pub struct ChannelContext {
+    pub animation: ItemRef,
+    pub node: ItemRef,
+}
+
+impl ChannelContext {
+    fn missing_values(&self, values: &'static str) -> FileError {
+        FileError::MissingSamplerValues {
+            animation: self.animation.clone(),
+            node: self.node.clone(),
+            values,
+        }
+    }
+
+    fn duplicate_keyframes(&self, property: &'static str) -> FileError {
+        FileError::DuplicateKeyframes {
+            animation: self.animation.clone(),
+            node: self.node.clone(),
+            property,
+        }
+    }
 }
 
 pub fn from_animations<'a>(
     doc_anims: impl Iterator<Item=gltf::Animation<'a>>,
     buffers: &[gltf::buffer::Data],
-) -> HashMap<NodeId, AnimationSet> {
+) -> Result<HashMap<NodeId, AnimationSet>, FileError> {
     let mut animations: HashMap<NodeId, AnimationSet> = HashMap::new();
 
     for anim_data in doc_anims {
         let anim_name = anim_data.name();
 
         for channel in anim_data.channels() {
-            let interpolation = channel.sampler().interpolation().into();
+            let target_node = channel.target().node();
+            let context = ChannelContext {
+                animation: ItemRef::new(anim_data.index(), anim_name),
+                node: ItemRef::new(target_node.index(), target_node.name()),
+            };
+
+            let interpolation = channel.sampler().interpolation();
+            let interpolation = Interpolation::from_gltf(interpolation)
+                .ok_or_else(|| FileError::UnsupportedInterpolation {
+                    animation: context.animation.clone(),
+                    node: context.node.clone(),
+                    interpolation: "cubic spline",
+                })?;
 
             // Create Animation
-            let node_id = NodeId::from_gltf(&channel.target().node());
+            let node_id = NodeId::from_gltf(&target_node);
             let anim_set = animations.entry(node_id).or_default();
 
             let anim = match anim_set.animation_mut(anim_name) {
@@ -140,9 +306,72 @@ pub fn from_animations<'a>(
                 None => anim_set.insert(Animation::with_name(anim_name)),
             };
 
-            anim.set_keyframes(channel, buffers, interpolation);
+            anim.set_keyframes(channel, buffers, interpolation, &context)?;
         }
     }
 
-    animations
+    Ok(animations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+    /// Returns true if both quaternions represent the same rotation (q and -q are equivalent)
+    fn same_rotation(a: Quaternion, b: Quaternion) -> bool {
+        let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+        (dot.abs() - 1.0).abs() < 1e-5
+    }
+
+    #[test]
+    fn quaternion_round_trip() {
+        let rotations = vec![
+            // trace > 0
+            Quaternion::identity(),
+            Quaternion::rotation_3d(0.7, Vec3::new(1.0, 2.0, 3.0)),
+            // trace <= 0, with the largest diagonal element in each column
+            Quaternion::rotation_x(PI),
+            Quaternion::rotation_y(PI),
+            Quaternion::rotation_z(PI),
+            Quaternion::rotation_3d(2.8, Vec3::new(1.0, 0.2, 0.1)),
+            Quaternion::rotation_3d(2.8, Vec3::new(0.1, 1.0, 0.2)),
+            Quaternion::rotation_3d(2.8, Vec3::new(0.2, 0.1, 1.0)),
+        ];
+
+        for rotation in rotations {
+            let actual = rotation_to_quaternion(Mat3::from(rotation));
+            assert!(same_rotation(actual, rotation), "expected {:?}, got {:?}", rotation, actual);
+        }
+    }
+
+    #[test]
+    fn blend_weights() {
+        let from = NodePose {
+            scale: Vec3::one(),
+            rotation: Quaternion::identity(),
+            translation: Vec3::zero(),
+        };
+        let to = NodePose {
+            scale: Vec3::new(3.0, 3.0, 3.0),
+            rotation: Quaternion::rotation_z(PI / 2.0),
+            translation: Vec3::new(2.0, -4.0, 8.0),
+        };
+
+        let start = from.blend(to, 0.0);
+        assert_eq!(start.scale, from.scale);
+        assert_eq!(start.translation, from.translation);
+        assert!(same_rotation(start.rotation, from.rotation));
+
+        let end = from.blend(to, 1.0);
+        assert_eq!(end.scale, to.scale);
+        assert_eq!(end.translation, to.translation);
+        assert!(same_rotation(end.rotation, to.rotation));
+
+        let half = from.blend(to, 0.5);
+        assert_eq!(half.scale, Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!(half.translation, Vec3::new(1.0, -2.0, 4.0));
+        assert!(same_rotation(half.rotation, Quaternion::rotation_z(PI / 4.0)));
+    }
 }
@@ -6,16 +6,17 @@ pub enum Interpolation {
     Step,
 }
 
-impl From<gltf::animation::Interpolation> for Interpolation {
-    fn from(interp: gltf::animation::Interpolation) -> Self {
+impl Interpolation {
+    /// Converts from a glTF interpolation method, returning None if the method is not supported
+    pub fn from_gltf(interp: gltf::animation::Interpolation) -> Option<Self> {
         use gltf::animation::Interpolation::*;
         match interp {
-            Linear => Interpolation::Linear,
-            Step => Interpolation::Step,
+            Linear => Some(Interpolation::Linear),
+            Step => Some(Interpolation::Step),
             //TODO - In order to support cubicspline interpolation, we need to change how we're
             // storing the data
             // https://github.com/KhronosGroup/glTF/tree/master/specification/2.0#animation-samplerinterpolation
-            CubicSpline => unimplemented!("Cubicspline interpolation is not supported!"),
+            CubicSpline => None,
         }
     }
 }
@@ -40,8 +40,11 @@ impl<T> Keyframes<T> {
     /// A time smaller than that of all keyframes will get back the first keyframe twice
     /// A time larger than all keyframes gets the last keyframe twice
     pub fn surrounding(&self, time: Milliseconds) -> KeyframeRange<T> {
-        // This unwrap is safe for partial_cmp as long as NaN is not one of the comparison values
-        let index = match self.frames.binary_search_by(|frame| frame.time.partial_cmp(&time).unwrap()) {
+        // Keyframe times are checked to be finite when they are loaded, so only the given time can
+        // be NaN. NaN is ordered before or after every keyframe (depending on its sign), so it gets
+        // the first or last keyframe instead of panicking.
+        let time = time.to_msec();
+        let index = match self.frames.binary_search_by(|frame| frame.time.to_msec().total_cmp(&time)) {
             Ok(i) | Err(i) => i,
         };
 
@@ -69,6 +72,10 @@ impl<T> Keyframes<T> {
             &AnimationPosition::RelativeTime{start_time, weight} => {
                 Milliseconds::from_msec(lerp(&start_time.to_msec(), &self.end_time().to_msec(), &weight))
             },
+            &AnimationPosition::LoopedTime{start_time, weight} => {
+                let weight = weight.rem_euclid(1.0);
+                Milliseconds::from_msec(lerp(&start_time.to_msec(), &self.end_time().to_msec(), &weight))
+            },
         };
 
         let new_value = match self.surrounding(time) {
@@ -92,3 +99,23 @@ pub struct Frame<T> {
     pub time: Milliseconds,
     pub value: T,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nan_time_does_not_panic() {
+        let times = vec![0.0, 10.0, 20.0].into_iter().map(Milliseconds::from_msec);
+        let keyframes = Keyframes::new(times, vec![1, 2, 3].into_iter(), Interpolation::Linear);
+
+        match keyframes.surrounding(Milliseconds::from_msec(f32::NAN)) {
+            KeyframeRange::Before(frame) => assert_eq!(frame.value, 3),
+            _ => panic!("expected the last keyframe"),
+        }
+        match keyframes.surrounding(Milliseconds::from_msec(15.0)) {
+            KeyframeRange::Between(left, right) => assert_eq!((left.value, right.value), (2, 3)),
+            _ => panic!("expected the surrounding keyframes"),
+        }
+    }
+}
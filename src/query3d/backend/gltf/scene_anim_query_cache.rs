@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use approx::relative_eq;
 
-use crate::query3d::{AnimationQuery, AnimationPosition};
+use crate::query3d::{AnimationQuery, AnimationPosition, RootMotionLock};
 
 /// If this value is too small, our cache will be bloated. If the value is too big, the cache will
 /// incorrectly treat different values as the same.
@@ -12,13 +12,15 @@ const TOLERANCE: f32 = 0.0001;
 /// Defines a total ordering for floating point numbers by treating numbers that are approximately
 /// the same as equal.
 ///
-/// This does not account for NaN values and will probably panic if they are encountered.
+/// NaN values are all equal to each other and ordered after every other value, regardless of
+/// their sign or payload.
 fn approx_cmp(left: f32, right: f32) -> Ordering {
-    if relative_eq!(left, right, epsilon = TOLERANCE) {
-        Ordering::Equal
-    } else {
-        // This will panic if we get NaN
-        left.partial_cmp(&right).unwrap()
+    match (left.is_nan(), right.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) if relative_eq!(left, right, epsilon = TOLERANCE) => Ordering::Equal,
+        (false, false) => left.total_cmp(&right),
     }
 }
 
@@ -26,16 +28,16 @@ fn approx_cmp(left: f32, right: f32) -> Ordering {
 fn pos_cmp(left: &AnimationPosition, right: &AnimationPosition) -> Ordering {
     use AnimationPosition::*;
     match (left, right) {
-        // Arbitrarily order the different variants
-        (Time(_), RelativeTime {..}) => Ordering::Less,
-        (RelativeTime {..}, Time(_)) => Ordering::Greater,
-
         // Compare the values if the variants are the same
         (Time(left), Time(right)) => approx_cmp(left.to_msec(), right.to_msec()),
 
         (
             &RelativeTime {start_time: left_start_time, weight: left_weight},
             &RelativeTime {start_time: right_start_time, weight: right_weight},
+        ) |
+        (
+            &LoopedTime {start_time: left_start_time, weight: left_weight},
+            &LoopedTime {start_time: right_start_time, weight: right_weight},
         ) => {
             // Arbitrarily decided to order by start_time first and then weight
             match approx_cmp(left_start_time.to_msec(), right_start_time.to_msec()) {
@@ -43,17 +45,45 @@ fn pos_cmp(left: &AnimationPosition, right: &AnimationPosition) -> Ordering {
                 order => order,
             }
         },
+
+        // Arbitrarily order the different variants
+        _ => variant_index(left).cmp(&variant_index(right)),
+    }
+}
+
+fn variant_index(pos: &AnimationPosition) -> u8 {
+    use AnimationPosition::*;
+    match pos {
+        Time(_) => 0,
+        RelativeTime {..} => 1,
+        LoopedTime {..} => 2,
     }
 }
 
+/// Defines a total ordering for the positions and weights of two queries for the same animations
+fn query_cmp(left: &AnimationQuery, right: &AnimationQuery) -> Ordering {
+    let left_layers = left.layers.iter().map(|layer| (&layer.position, layer.weight));
+    let right_layers = right.layers.iter().map(|layer| (&layer.position, layer.weight));
+
+    // Since the queries are for the same animations, they have the same number of layers
+    left_layers.zip(right_layers).fold(pos_cmp(&left.position, &right.position),
+        |order, ((left_pos, left_weight), (right_pos, right_weight))| {
+            order
+                .then_with(|| pos_cmp(left_pos, right_pos))
+                .then_with(|| approx_cmp(left_weight, right_weight))
+        })
+}
+
 /// A cache for animation positions
 ///
 /// The idea is that because floating point numbers can't be hashed, we'll instead define a
 /// tolerance within which two floating point numbers are considered equal. We can then use
 /// binary search to fairly accurately find a match. Note that we are ignoring NaN here.
+///
+/// The positions and weights of any layers are treated the same way.
 #[derive(Debug)]
 pub struct AnimPosCache<T> {
-    entries: Vec<(AnimationPosition, T)>,
+    entries: Vec<(AnimationQuery, T)>,
 }
 
 // Need to manually implement default because the derive requires T: Default
@@ -66,27 +96,38 @@ impl<T> Default for AnimPosCache<T> {
 }
 
 impl<T> AnimPosCache<T> {
-    pub fn get(&self, pos: &AnimationPosition) -> Option<&T> {
-        self.find(pos).ok().map(|i| &self.entries[i].1)
+    pub fn get(&self, query: &AnimationQuery) -> Option<&T> {
+        self.find(query).ok().map(|i| &self.entries[i].1)
     }
 
-    pub fn insert(&mut self, pos: &AnimationPosition, value: T) {
-        match self.find(pos) {
+    pub fn insert(&mut self, query: &AnimationQuery, value: T) {
+        match self.find(query) {
             Ok(_) => unreachable!("bug: attempt to re-insert a cached value"),
-            Err(i) => self.entries.insert(i, (pos.clone(), value)),
+            Err(i) => self.entries.insert(i, (query.clone(), value)),
         }
     }
 
-    fn find(&self, pos: &AnimationPosition) -> Result<usize, usize> {
-        self.entries.binary_search_by(|(epos, _)| pos_cmp(epos, pos))
+    fn find(&self, query: &AnimationQuery) -> Result<usize, usize> {
+        self.entries.binary_search_by(|(equery, _)| query_cmp(equery, query))
     }
 }
 
+/// The names of the animations and masks used in an animation query, along with its root motion
+/// lock (since that doesn't depend on the animation position either)
+type AnimationNames = (Option<String>, Vec<(String, Option<String>)>, Option<RootMotionLock>);
+
+fn animation_names(anim_query: &AnimationQuery) -> AnimationNames {
+    let AnimationQuery {name, position: _, layers, root_motion} = anim_query;
+    let layers = layers.iter().map(|layer| (layer.name.clone(), layer.mask.clone())).collect();
+
+    (name.clone(), layers, root_motion.clone())
+}
+
 /// A cache based on the scene index and the animation query
 #[derive(Debug)]
 pub struct SceneAnimQueryCache<T> {
-    /// A cache of (scene index, animation name) to a cache for the animation positions
-    cache: HashMap<(usize, Option<String>), AnimPosCache<T>>,
+    /// A cache of (scene index, animation names) to a cache for the animation positions
+    cache: HashMap<(usize, AnimationNames), AnimPosCache<T>>,
 }
 
 // Need to manually implement default because the derive requires T: Default
@@ -100,16 +141,35 @@ impl<T> Default for SceneAnimQueryCache<T> {
 
 impl<T> SceneAnimQueryCache<T> {
     pub fn get(&self, scene_index: usize, anim_query: &AnimationQuery) -> Option<&T> {
-        let AnimationQuery {name, position} = anim_query;
         //TODO: There are potentially some (complex) ways to get around the allocation here, but
         // it's probably not worth the effort so I opted to ignore it for now.
-        self.cache.get(&(scene_index, name.clone()))
-            .and_then(|pos_cache| pos_cache.get(position))
+        self.cache.get(&(scene_index, animation_names(anim_query)))
+            .and_then(|pos_cache| pos_cache.get(anim_query))
     }
 
     pub fn insert(&mut self, scene_index: usize, anim_query: &AnimationQuery, value: T) {
-        let AnimationQuery {name, position} = anim_query;
-        let pos_cache = self.cache.entry((scene_index, name.clone())).or_default();
-        pos_cache.insert(position, value);
+        let pos_cache = self.cache.entry((scene_index, animation_names(anim_query))).or_default();
+        pos_cache.insert(anim_query, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nan_is_ordered_last() {
+        let negative_nan = -f32::NAN;
+        assert!(negative_nan.is_sign_negative());
+
+        for &nan in &[f32::NAN, negative_nan] {
+            assert_eq!(approx_cmp(nan, f32::INFINITY), Ordering::Greater);
+            assert_eq!(approx_cmp(f32::NEG_INFINITY, nan), Ordering::Less);
+            assert_eq!(approx_cmp(nan, f32::NAN), Ordering::Equal);
+            assert_eq!(approx_cmp(nan, negative_nan), Ordering::Equal);
+        }
+
+        assert_eq!(approx_cmp(1.0, 1.0 + TOLERANCE / 10.0), Ordering::Equal);
+        assert_eq!(approx_cmp(1.0, 2.0), Ordering::Less);
     }
 }
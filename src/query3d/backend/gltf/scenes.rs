@@ -33,6 +33,19 @@ impl Scenes {
                 .ok_or_else(|| QueryError::UnknownScene {name: name.to_string()}),
         }
     }
+
+    /// Returns the name of every scene, in order
+    pub fn names(&self) -> Result<Vec<String>, QueryError> {
+        self.scenes.iter().enumerate()
+            .map(|(index, scene)| scene.name.clone().ok_or(QueryError::UnnamedScene {index}))
+            .collect()
+    }
+
+    /// Iterates over every scene, along with whether it is the default scene
+    pub fn iter(&self) -> impl Iterator<Item=(&Arc<Scene>, bool)> {
+        let default_scene = self.default_scene;
+        self.scenes.iter().enumerate().map(move |(index, scene)| (scene, index == default_scene))
+    }
 }
 
 impl Index<usize> for Scenes {
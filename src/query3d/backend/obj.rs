@@ -1,12 +1,26 @@
 use std::path::Path;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use rayon::iter::{ParallelIterator, IntoParallelIterator};
 
-use crate::math::Mat4;
+use crate::math::{Mat4, Vec3, Milliseconds};
 use crate::scene::{Mesh, Material};
-use crate::renderer::{Display, ShaderGeometry, JointMatrixTexture, Camera, Light};
-use crate::query3d::{GeometryQuery, GeometryFilter, AnimationQuery, CameraQuery, LightQuery};
+use crate::renderer::{Display, ShaderGeometry, GeometryBuffers, JointMatrixTexture, Camera, Light};
+use crate::query3d::{
+    GeometryQuery,
+    GeometryFilter,
+    AnimationQuery,
+    NodeQuery,
+    CameraQuery,
+    LightQuery,
+    ModelInfo,
+    SceneInfo,
+    NodeInfo,
+    NodeKind,
+    MaterialInfo,
+    BoundingBox,
+};
 
 use super::{QueryBackend, QueryError};
 
@@ -17,6 +31,8 @@ pub struct ObjFile {
     // change once we add in more advanced filtering (e.g. by name)
 
     mesh: Mesh,
+    /// Every material in the file, whether or not it is used
+    materials: Vec<Arc<Material>>,
     /// The version of this model lazily uploaded to the GPU
     scene_geometry: Option<Arc<Vec<Arc<ShaderGeometry>>>>,
     /// Cache the default joint matrix texture so we don't upload it over and over again
@@ -34,26 +50,31 @@ impl ObjFile {
 
         Ok(Self {
             mesh: Mesh::from_obj(models, &materials),
+            materials,
             scene_geometry: None,
             default_joint_matrix_texture: None,
         })
     }
 }
 
+/// OBJ files do not support animations, so any animation query results in an error
+fn check_no_animation(animation: &Option<AnimationQuery>) -> Result<(), QueryError> {
+    match animation {
+        Some(AnimationQuery {name: Some(name), ..}) => Err(QueryError::UnknownAnimation {name: name.clone()}),
+        Some(AnimationQuery {name: None, ..}) => Err(QueryError::NoAnimationFound),
+        None => Ok(()),
+    }
+}
+
 impl QueryBackend for ObjFile {
+    // The geometry holds GPU resources that belong to the render context of the current thread. It
+    // is only shared between the frames drawn on this thread, so it never needs to be `Send` or
+    // `Sync`.
+    #[allow(clippy::arc_with_non_send_sync)]
     fn query_geometry(&mut self, query: &GeometryQuery, display: &Display) -> Result<Arc<Vec<Arc<ShaderGeometry>>>, QueryError> {
         let GeometryQuery {models, animation} = query;
 
-        // OBJ files do not support animations
-        match animation {
-            Some(AnimationQuery {name: Some(name), ..}) => {
-                return Err(QueryError::UnknownAnimation {name: name.clone()});
-            },
-            Some(AnimationQuery {name: None, ..}) => {
-                return Err(QueryError::NoAnimationFound);
-            },
-            _ => {},
-        }
+        check_no_animation(animation)?;
 
         use GeometryFilter::*;
         match models {
@@ -73,10 +94,16 @@ impl QueryBackend for ObjFile {
 
                     let scene_geometry = Arc::new(self.mesh.geometry.iter()
                         .map(|geo| {
-                            ShaderGeometry::new(display, geo, &joint_matrices_tex, Mat4::identity(),
-                                |_| panic!("bug: textures are not supported for OBJ files")).map(Arc::new)
+                            let buffers = GeometryBuffers::new(display, geo,
+                                |_| panic!("bug: textures are not supported for OBJ files"))?;
+
+                            Ok(Arc::new(ShaderGeometry {
+                                buffers: Rc::new(buffers),
+                                joint_matrices: joint_matrices_tex.clone(),
+                                model_transform: Mat4::identity(),
+                            }))
                         })
-                        .collect::<Result<Vec<_>, _>>()?);
+                        .collect::<Result<Vec<_>, QueryError>>()?);
 
                     if scene_geometry.is_empty() {
                         return Err(QueryError::NoGeometryFound);
@@ -92,16 +119,35 @@ impl QueryBackend for ObjFile {
         }
     }
 
+    fn check_geometry(&self, query: &GeometryQuery) -> Result<(), QueryError> {
+        let GeometryQuery {models, animation} = query;
+
+        check_no_animation(animation)?;
+
+        use GeometryFilter::*;
+        match models {
+            Scene {name: None} if self.mesh.geometry.is_empty() => Err(QueryError::NoGeometryFound),
+            Scene {name: None} => Ok(()),
+            // OBJ files do not contain any named scenes
+            Scene {name: Some(name)} => Err(QueryError::UnknownScene {name: name.clone()}),
+        }
+    }
+
     fn query_camera(&mut self, query: &CameraQuery) -> Result<Arc<Camera>, QueryError> {
         // OBJ files do not support cameras
         // This code still does the work to produce useful errors
         match query {
-            CameraQuery::FirstInScene {name: None} => Err(QueryError::NoCameraFound),
-            CameraQuery::Named {name, scene: None} => Err(QueryError::UnknownCamera {name: name.clone()}),
+            CameraQuery::FirstInScene {animation, ..} |
+            CameraQuery::Named {animation, ..} => check_no_animation(animation)?,
+        }
+
+        match query {
+            CameraQuery::FirstInScene {name: None, ..} => Err(QueryError::NoCameraFound),
+            CameraQuery::Named {name, scene: None, ..} => Err(QueryError::UnknownCamera {name: name.clone()}),
 
             // OBJ files do not contain any named scenes
-            CameraQuery::FirstInScene {name: Some(name)} |
-            CameraQuery::Named {name: _, scene: Some(name)} => Err(QueryError::UnknownScene {
+            CameraQuery::FirstInScene {name: Some(name), ..} |
+            CameraQuery::Named {name: _, scene: Some(name), ..} => Err(QueryError::UnknownScene {
                 name: name.clone(),
             }),
         }
@@ -111,14 +157,81 @@ impl QueryBackend for ObjFile {
         // OBJ files do not support lights
         // This code still does the work to produce useful errors
         match query {
-            LightQuery::Scene {name: None} => Err(QueryError::NoLightsFound),
-            LightQuery::Named {name, scene: None} => Err(QueryError::UnknownCamera {name: name.clone()}),
+            LightQuery::Scene {animation, ..} |
+            LightQuery::Named {animation, ..} => check_no_animation(animation)?,
+        }
+
+        match query {
+            LightQuery::Scene {name: None, ..} => Err(QueryError::NoLightsFound),
+            LightQuery::Named {name, scene: None, ..} => Err(QueryError::UnknownCamera {name: name.clone()}),
 
             // OBJ files do not contain any named scenes
-            LightQuery::Scene {name: Some(name)} |
-            LightQuery::Named {name: _, scene: Some(name)} => Err(QueryError::UnknownScene {
+            LightQuery::Scene {name: Some(name), ..} |
+            LightQuery::Named {name: _, scene: Some(name), ..} => Err(QueryError::UnknownScene {
                 name: name.clone(),
             }),
         }
     }
+
+    fn query_scene_names(&self) -> Result<Vec<String>, QueryError> {
+        // OBJ files do not contain any named scenes
+        Ok(Vec::new())
+    }
+
+    fn query_animation_end_time(&self, name: Option<&str>) -> Result<Milliseconds, QueryError> {
+        // OBJ files do not support animations
+        match name {
+            Some(name) => Err(QueryError::UnknownAnimation {name: name.to_string()}),
+            None => Err(QueryError::NoAnimationFound),
+        }
+    }
+
+    fn query_root_motion(&self, query: &AnimationQuery, _scene: Option<&str>) -> Result<Vec3, QueryError> {
+        // OBJ files do not support animations
+        match &query.name {
+            Some(name) => Err(QueryError::UnknownAnimation {name: name.clone()}),
+            None => Err(QueryError::NoAnimationFound),
+        }
+    }
+
+    fn query_node_transform(&self, query: &NodeQuery) -> Result<Mat4, QueryError> {
+        let NodeQuery {name, scene, animation} = query;
+
+        check_no_animation(animation)?;
+
+        match scene {
+            // OBJ files do not contain any named nodes
+            None => Err(QueryError::UnknownNode {name: name.clone()}),
+            // OBJ files do not contain any named scenes
+            Some(scene) => Err(QueryError::UnknownScene {name: scene.clone()}),
+        }
+    }
+
+    fn query_mesh_positions(&self, query: &NodeQuery) -> Result<Vec<Vec3>, QueryError> {
+        // OBJ files do not contain any named nodes, so this fails in the same way
+        self.query_node_transform(query).map(|_| Vec::new())
+    }
+
+    fn inspect(&self) -> ModelInfo {
+        let positions = self.mesh.geometry.iter().flat_map(|geo| geo.positions.iter().copied());
+
+        ModelInfo {
+            // OBJ files are loaded as a single unnamed scene containing a single mesh
+            scenes: vec![SceneInfo {
+                name: None,
+                default: true,
+                nodes: vec![NodeInfo {
+                    name: self.mesh.name.clone(),
+                    kind: NodeKind::Mesh,
+                    children: Vec::new(),
+                }],
+                bounds: BoundingBox::from_points(positions),
+            }],
+            // OBJ files do not support animations, cameras or lights
+            animations: Vec::new(),
+            cameras: Vec::new(),
+            lights: Vec::new(),
+            materials: self.materials.iter().map(|mat| MaterialInfo::from(&**mat)).collect(),
+        }
+    }
 }
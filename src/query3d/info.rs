@@ -0,0 +1,207 @@
+use serde::Serialize;
+
+use crate::math::{Vec3, Milliseconds};
+use crate::scene::{NodeTree, NodeId, Node, NodeData, Scene, Material, CameraType, LightType};
+
+/// A summary of everything in a model file
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelInfo {
+    pub scenes: Vec<SceneInfo>,
+    pub animations: Vec<AnimationInfo>,
+    pub cameras: Vec<CameraInfo>,
+    pub lights: Vec<LightInfo>,
+    pub materials: Vec<MaterialInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SceneInfo {
+    /// The name of the scene, or None if the file does not support scene names
+    pub name: Option<String>,
+    /// True if this scene is used when no scene name is provided
+    pub default: bool,
+    /// The root nodes of the scene and their children
+    pub nodes: Vec<NodeInfo>,
+    /// The bounds of all the geometry in the scene in world coordinates, without any animations
+    /// applied, or None if the scene has no geometry
+    pub bounds: Option<BoundingBox>,
+}
+
+impl SceneInfo {
+    pub(in super) fn from_scene(scene: &Scene, default: bool, nodes: &NodeTree) -> Self {
+        let bounds = scene.roots.iter()
+            .flat_map(|&root| nodes.traverse(root))
+            .filter_map(|(parent_trans, node)| {
+                let (mesh, _) = node.mesh()?;
+                let transform = parent_trans * node.transform;
+                let points = mesh.geometry.iter()
+                    .flat_map(|geo| &geo.positions)
+                    .map(|&pos| transform.mul_point(pos));
+                BoundingBox::from_points(points)
+            })
+            .fold(None, |bounds: Option<BoundingBox>, node_bounds| match bounds {
+                Some(bounds) => Some(bounds.union(node_bounds)),
+                None => Some(node_bounds),
+            });
+
+        Self {
+            name: scene.name.clone(),
+            default,
+            nodes: scene.roots.iter().map(|&root| NodeInfo::from_node(root, nodes)).collect(),
+            bounds,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeInfo {
+    /// The name of the node, or None if the file does not support node names
+    pub name: Option<String>,
+    /// The kind of data stored in the node
+    pub kind: NodeKind,
+    pub children: Vec<NodeInfo>,
+}
+
+impl NodeInfo {
+    fn from_node(id: NodeId, nodes: &NodeTree) -> Self {
+        let Node {name, data, ..} = nodes.get(id);
+
+        let kind = match data {
+            None => NodeKind::Empty,
+            Some(NodeData::Mesh(..)) => NodeKind::Mesh,
+            Some(NodeData::Camera(_)) => NodeKind::Camera,
+            Some(NodeData::Light(_)) => NodeKind::Light,
+        };
+
+        Self {
+            name: name.clone(),
+            kind,
+            children: nodes.children(id).map(|child| Self::from_node(child.id, nodes)).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+    Empty,
+    Mesh,
+    Camera,
+    Light,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AnimationInfo {
+    /// The name of the animation, if any
+    pub name: Option<String>,
+    /// The time in ms of the last keyframe of the animation, across every node it animates
+    pub duration: Milliseconds,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CameraInfo {
+    /// The name of the camera, if any
+    pub name: Option<String>,
+    /// The name of the node that contains the camera, if any
+    pub node: Option<String>,
+    pub projection: Projection,
+}
+
+impl CameraInfo {
+    pub(in super) fn from_camera(cam: &CameraType, node: &Node) -> Self {
+        let projection = match cam {
+            CameraType::Perspective {..} => Projection::Perspective,
+            CameraType::Orthographic {..} => Projection::Orthographic,
+        };
+
+        Self {
+            name: cam.name().map(String::from),
+            node: node.name.clone(),
+            projection,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Projection {
+    Perspective,
+    Orthographic,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LightInfo {
+    /// The name of the light, if any
+    pub name: Option<String>,
+    /// The name of the node that contains the light, if any
+    pub node: Option<String>,
+    pub kind: LightKind,
+}
+
+impl LightInfo {
+    pub(in super) fn from_light(light: &LightType, node: &Node) -> Self {
+        let kind = match light {
+            LightType::Point {..} => LightKind::Point,
+            LightType::Directional {..} => LightKind::Directional,
+            LightType::Spot {..} => LightKind::Spot,
+        };
+
+        Self {
+            name: light.name().map(String::from),
+            node: node.name.clone(),
+            kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LightKind {
+    Point,
+    Directional,
+    Spot,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaterialInfo {
+    /// The name of the material, if any
+    pub name: Option<String>,
+    /// True if the material uses a texture for its color
+    pub textured: bool,
+}
+
+impl From<&Material> for MaterialInfo {
+    fn from(mat: &Material) -> Self {
+        Self {
+            name: mat.name.clone(),
+            textured: mat.texture.is_some(),
+        }
+    }
+}
+
+/// An axis-aligned box
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BoundingBox {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl BoundingBox {
+    /// Returns the smallest box containing all of the given points, or None if there are no points
+    pub fn from_points(points: impl Iterator<Item=Vec3>) -> Option<Self> {
+        points.fold(None, |bounds, point| Some(match bounds {
+            Some(Self {min, max}) => Self {
+                min: Vec3::partial_min(min, point),
+                max: Vec3::partial_max(max, point),
+            },
+            None => Self {min: point, max: point},
+        }))
+    }
+
+    /// Returns the smallest box containing both boxes
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            min: Vec3::partial_min(self.min, other.min),
+            max: Vec3::partial_max(self.max, other.max),
+        }
+    }
+}
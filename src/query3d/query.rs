@@ -27,6 +27,45 @@ pub struct AnimationQuery {
     pub name: Option<String>,
     /// The position in the animation to retrieve the current state from
     pub position: AnimationPosition,
+    /// Additional animations to blend on top of the animation above, applied in order
+    pub layers: Vec<AnimationLayer>,
+    /// Keeps a node in place by removing the translation added by the animations, or None if
+    /// the animations should be applied as is
+    pub root_motion: Option<RootMotionLock>,
+}
+
+/// Locks the translation of a node (and therefore everything attached to it) on certain axes
+///
+/// This is used to play animations that move the whole model (e.g. walk cycles) in place.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RootMotionLock {
+    /// The name of the node to lock or None if every root node of the model should be locked
+    pub node: Option<String>,
+    /// True if the translation along the x-axis of the node's parent should be locked
+    pub x: bool,
+    /// True if the translation along the y-axis of the node's parent should be locked
+    pub y: bool,
+    /// True if the translation along the z-axis of the node's parent should be locked
+    pub z: bool,
+}
+
+/// An animation that is blended with the animations beneath it
+///
+/// For example, a walk and a run can be cross-faded by layering the run with a weight of 0.5 over
+/// the walk, or an attack can replace the upper body of a run by masking it to the spine.
+#[derive(Debug, Clone)]
+pub struct AnimationLayer {
+    /// The name of the animation to blend in
+    pub name: String,
+    /// The position in the animation to retrieve the current state from
+    pub position: AnimationPosition,
+    /// How much this animation replaces the animations beneath it. A weight of 0.0 has no effect
+    /// and a weight of 1.0 replaces the components (scale, rotation, translation) that this
+    /// animation animates entirely.
+    pub weight: f32,
+    /// The name of a node whose subtree (including itself) is the only part of the scene affected
+    /// by this animation, or None if the entire scene should be affected
+    pub mask: Option<String>,
 }
 
 /// Represents the position in a given animation
@@ -44,6 +83,27 @@ pub enum AnimationPosition {
         /// as the time of its last keyframe.
         weight: f32,
     },
+    /// Like `RelativeTime`, but the weight wraps around so that the animation can be looped
+    ///
+    /// A weight of 1.0 is the same position as 0.0, 1.25 is the same as 0.25, and so on.
+    LoopedTime {
+        /// The start time in ms
+        start_time: Milliseconds,
+        /// The interpolation factor between the provided start time and the end of the
+        /// animation. Only the fractional part of this value is used.
+        weight: f32,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeQuery {
+    /// The name of the node to look for
+    pub name: String,
+    /// The name of the scene to look in or None if the default scene should be used
+    pub scene: Option<String>,
+    /// The animation to apply to the scene before finding the node, or None if the node should
+    /// be found in its original (unanimated) position
+    pub animation: Option<AnimationQuery>,
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +112,9 @@ pub enum CameraQuery {
     FirstInScene {
         /// The name of the scene to look in or None if the default scene should be used
         name: Option<String>,
+        /// The animation to apply to the scene before finding the camera, or None if the camera
+        /// should be found in its original (unanimated) position
+        animation: Option<AnimationQuery>,
     },
     /// Returns the camera with the given name
     Named {
@@ -59,12 +122,15 @@ pub enum CameraQuery {
         name: String,
         /// The name of the scene to look in or None if the default scene should be used
         scene: Option<String>,
+        /// The animation to apply to the scene before finding the camera, or None if the camera
+        /// should be found in its original (unanimated) position
+        animation: Option<AnimationQuery>,
     },
 }
 
 impl CameraQuery {
     pub fn first_in_default_scene() -> Self {
-        CameraQuery::FirstInScene {name: None}
+        CameraQuery::FirstInScene {name: None, animation: None}
     }
 }
 
@@ -74,6 +140,9 @@ pub enum LightQuery {
     Scene {
         /// The name of the scene to look in or None if the default scene should be used
         name: Option<String>,
+        /// The animation to apply to the scene before finding the lights, or None if the lights
+        /// should be found in their original (unanimated) positions
+        animation: Option<AnimationQuery>,
     },
     /// Returns the light with the given name
     Named {
@@ -81,11 +150,14 @@ pub enum LightQuery {
         name: String,
         /// The name of the scene to look in or None if the default scene should be used
         scene: Option<String>,
+        /// The animation to apply to the scene before finding the lights, or None if the lights
+        /// should be found in their original (unanimated) positions
+        animation: Option<AnimationQuery>,
     },
 }
 
 impl LightQuery {
     pub fn all_in_default_scene() -> Self {
-        LightQuery::Scene {name: None}
+        LightQuery::Scene {name: None, animation: None}
     }
 }
@@ -1,4 +1,5 @@
 mod thread_render_context;
+mod pending_image;
 mod shader_material;
 mod shader_geometry;
 mod joint_matrices_texture;
@@ -6,13 +7,17 @@ mod render_node;
 mod rendered_image;
 mod job;
 mod light;
+mod light_texture;
+mod alpha_output;
 mod camera;
+mod color_transfer;
+mod shadow_map;
 
 mod layout;
 mod shader;
-mod imageops;
 
 pub use thread_render_context::*;
+pub use pending_image::*;
 pub use shader_material::*;
 pub use shader_geometry::*;
 pub use joint_matrices_texture::*;
@@ -20,16 +25,35 @@ pub use render_node::*;
 pub use rendered_image::*;
 pub use job::*;
 pub use light::*;
+pub use light_texture::*;
+pub use alpha_output::*;
 pub use camera::*;
-
-use std::sync::Arc;
+pub use color_transfer::*;
+pub use shadow_map::*;
 
 use glium::{Surface, framebuffer::SimpleFrameBuffer};
 
-use crate::math::{Rgba, Rgb, Mat4};
+use crate::math::{Rgba, Rgb, Vec4, Mat4};
 
 use shader::cel::CelUniforms;
 use shader::outline::OutlineUniforms;
+use shader::ground::GroundUniforms;
+
+/// The lighting used when drawing models
+pub struct Lighting<'a> {
+    pub lights: &'a LightTexture,
+    /// The index in `lights` of the light that casts the shadows in `shadow_map`, if any
+    pub shadow_light: Option<usize>,
+    pub ambient_light: Rgb,
+    /// The color that the darkest band of the cel shading is multiplied by
+    pub shadow_tint: Rgb,
+    /// The shadows cast by one of the lights, if any
+    pub shadow_map: Option<&'a ShadowMap>,
+    /// The rim lights and specular highlights of each material
+    pub highlights: &'a MaterialHighlights,
+    /// The transfer function used to encode the lit colors in the rendered image
+    pub color_transfer: ColorTransfer,
+}
 
 /// A renderer that allows you to draw models
 pub struct Renderer<'a> {
@@ -54,8 +78,7 @@ impl<'a> Renderer<'a> {
     pub fn render(
         &mut self,
         geometry: &ShaderGeometry,
-        lights: &[Arc<Light>],
-        ambient_light: Rgb,
+        lighting: &Lighting,
         view: Mat4,
         projection: Mat4,
         outline: &Outline,
@@ -84,35 +107,64 @@ impl<'a> Renderer<'a> {
             ..Default::default()
         };
 
-        let ShaderGeometry {
+        let ShaderGeometry {buffers, joint_matrices, model_transform} = geometry;
+        let GeometryBuffers {
             indices,
             positions,
             normals,
             tex_coords,
             joint_influences,
             joint_weights,
-            joint_matrices,
             material,
-            model_transform,
-        } = geometry;
+        } = &**buffers;
+
+        let &Lighting {
+            lights,
+            shadow_light,
+            ambient_light,
+            shadow_tint,
+            shadow_map,
+            highlights,
+            color_transfer,
+        } = lighting;
 
         let model_transform = *model_transform;
         let mvp = projection * view * model_transform;
         let model_inverse_transpose = model_transform.inverted().transposed();
 
+        // Orthographic projections have no perspective divide, so every ray from the camera
+        // travels in the same direction
+        let camera_transform = view.inverted();
+        let camera_position = if projection.cols.w.w == 1.0 {
+            (camera_transform * Vec4::unit_z()).normalized()
+        } else {
+            camera_transform.cols.w
+        };
+
         let cel_uniforms = shader::cel::Cel::from(CelUniforms {
             mvp,
             model_transform,
             model_inverse_transpose,
             joint_matrices,
+            camera_position,
             lights,
+            shadow_light,
             ambient_light,
+            shadow_tint,
+            shadow_map,
             material,
+            highlights: highlights.get(material.name.as_deref()),
+            color_transfer,
         });
 
         self.target.draw((positions, normals, tex_coords, joint_influences, joint_weights), indices,
             &self.shaders.cel, &cel_uniforms, &cel_params)?;
 
+        // The outline relies on back faces, so only triangles can have an outline
+        if indices.get_primitives_type() != glium::index::PrimitiveType::TrianglesList {
+            return Ok(());
+        }
+
         let outline_uniforms = shader::outline::Outline::from(OutlineUniforms {
             mvp,
             joint_matrices,
@@ -125,4 +177,54 @@ impl<'a> Renderer<'a> {
 
         Ok(())
     }
+
+    /// Draws a drop shadow on an invisible ground plane
+    ///
+    /// This should be called after all the models have been drawn so that the shadow is hidden
+    /// wherever it is behind a model.
+    pub fn render_drop_shadow(
+        &mut self,
+        drop_shadow: &DropShadow,
+        extent: f32,
+        view: Mat4,
+        projection: Mat4,
+        shadow_map: Option<&ShadowMap>,
+    ) -> Result<(), glium::DrawError> {
+        let params = glium::DrawParameters {
+            depth: glium::Depth {
+                test: glium::draw_parameters::DepthTest::IfLess,
+                // The ground is invisible, so it should never hide anything drawn after it
+                write: false,
+                ..Default::default()
+            },
+            // Blends the shadow with the background. The alpha is accumulated as well so that
+            // the shadow is still visible over a transparent background.
+            blend: glium::Blend {
+                color: glium::BlendingFunction::Addition {
+                    source: glium::LinearBlendingFactor::SourceAlpha,
+                    destination: glium::LinearBlendingFactor::OneMinusSourceAlpha,
+                },
+                alpha: glium::BlendingFunction::Addition {
+                    source: glium::LinearBlendingFactor::One,
+                    destination: glium::LinearBlendingFactor::OneMinusSourceAlpha,
+                },
+                constant_value: (0.0, 0.0, 0.0, 0.0),
+            },
+            ..Default::default()
+        };
+
+        let ground_uniforms = shader::ground::Ground::from(GroundUniforms {
+            view_projection: projection * view,
+            extent,
+            drop_shadow,
+            shadow_map,
+        });
+
+        // The ground shader generates the corners of the plane, so no vertex data is needed
+        let vertices = glium::vertex::EmptyVertexAttributes {len: 4};
+        let indices = glium::index::NoIndices(glium::index::PrimitiveType::TriangleStrip);
+        self.target.draw(vertices, indices, &self.shaders.ground, &ground_uniforms, &params)?;
+
+        Ok(())
+    }
 }
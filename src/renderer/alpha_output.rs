@@ -0,0 +1,120 @@
+use std::collections::VecDeque;
+
+use image::{RgbaImage, Rgba};
+
+/// Controls how the alpha channel of a generated image is written
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AlphaOutput {
+    /// How the alpha of each pixel is stored
+    pub mode: AlphaMode,
+    /// If true, partially transparent pixels along the edges of the image content are removed
+    /// and fully transparent pixels are cleared to transparent black
+    pub remove_fringe: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum AlphaMode {
+    /// The color of each pixel is stored separately from its alpha
+    #[default]
+    Straight,
+    /// The color of each pixel is multiplied by its alpha
+    Premultiplied,
+    /// Every pixel is either fully opaque or fully transparent. Pixels with an alpha greater than
+    /// or equal to the threshold (between 0.0 and 1.0) become opaque.
+    Threshold(f32),
+}
+
+impl AlphaOutput {
+    /// Applies the alpha settings to the given image
+    pub fn apply(self, image: &mut RgbaImage) {
+        let Self {mode, remove_fringe} = self;
+
+        if remove_fringe {
+            self::remove_fringe(image);
+        }
+
+        use AlphaMode::*;
+        match mode {
+            Straight => {},
+
+            Premultiplied => for pixel in image.pixels_mut() {
+                let alpha = pixel[3] as u32;
+                for value in pixel.0.iter_mut().take(3) {
+                    *value = ((*value as u32 * alpha + 127) / 255) as u8;
+                }
+            },
+
+            Threshold(threshold) => {
+                let threshold = (threshold * 255.0).round() as u32;
+                for pixel in image.pixels_mut() {
+                    if pixel[3] as u32 >= threshold && pixel[3] > 0 {
+                        pixel[3] = 255;
+                    } else {
+                        *pixel = Rgba([0, 0, 0, 0]);
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Removes every partially transparent pixel that is connected to a fully transparent pixel
+/// through other partially transparent pixels, and clears the color of every fully transparent
+/// pixel
+///
+/// These pixels form a fringe around the edges of the image content that shows up as a halo when
+/// the image is drawn over a different background. Partially transparent pixels surrounded by
+/// opaque pixels are left as is.
+fn remove_fringe(image: &mut RgbaImage) {
+    let (width, height) = image.dimensions();
+
+    let mut queue: VecDeque<(u32, u32)> = image.enumerate_pixels()
+        .filter(|(_, _, pixel)| pixel[3] == 0)
+        .map(|(x, y, _)| (x, y))
+        .collect();
+
+    for &(x, y) in &queue {
+        image.put_pixel(x, y, Rgba([0, 0, 0, 0]));
+    }
+
+    while let Some((x, y)) = queue.pop_front() {
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+
+        for &(nx, ny) in &neighbors {
+            if nx >= width || ny >= height {
+                continue;
+            }
+
+            let alpha = image.get_pixel(nx, ny)[3];
+            if alpha > 0 && alpha < 255 {
+                image.put_pixel(nx, ny, Rgba([0, 0, 0, 0]));
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fringe_is_removed_from_edges_only() {
+        // A row with: transparent, two fringe pixels, opaque, enclosed partial pixel, opaque
+        let alphas = [0, 100, 200, 255, 128, 255];
+        let mut image = RgbaImage::from_fn(alphas.len() as u32, 1, |x, _| {
+            Rgba([50, 60, 70, alphas[x as usize]])
+        });
+
+        AlphaOutput {mode: AlphaMode::Straight, remove_fringe: true}.apply(&mut image);
+
+        let result: Vec<_> = image.pixels().map(|pixel| pixel[3]).collect();
+        assert_eq!(result, vec![0, 0, 0, 255, 128, 255]);
+        assert_eq!(*image.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+    }
+}
@@ -1,4 +1,6 @@
-use crate::math::Mat4;
+use crate::math::{Mat4, Vec2, Vec3, Vec4};
+
+use super::Size;
 
 #[derive(Debug, Clone)]
 pub struct Camera {
@@ -7,3 +9,82 @@ pub struct Camera {
     /// The projection matrix of this camera
     pub projection: Mat4,
 }
+
+impl Camera {
+    /// Returns the pixel position of the given world position in an image of the given size drawn
+    /// with this camera, measured from the top left corner of the image
+    pub fn project_to_pixels(&self, position: Vec3, size: Size) -> Vec2 {
+        let &Self {view, projection} = self;
+        let Size {width, height} = size;
+
+        let clip = projection * view * Vec4::from_point(position);
+        let ndc = Vec3::from(clip) / clip.w;
+
+        // Normalized device coordinates go from -1 to 1 with y pointing up, but pixels start at
+        // the top left corner with y pointing down
+        Vec2 {
+            x: (ndc.x + 1.0) / 2.0 * width.get() as f32,
+            y: (1.0 - ndc.y) / 2.0 * height.get() as f32,
+        }
+    }
+
+    /// Returns this camera moved so that whatever it would have drawn at the pixel position `from`
+    /// is drawn at the pixel position `to` instead, in an image of the given size
+    pub fn shifted(&self, from: Vec2, to: Vec2, size: Size) -> Self {
+        let &Self {view, projection} = self;
+        let Size {width, height} = size;
+
+        // Translating in clip space moves everything by the same amount on the screen, regardless
+        // of the depth or the type of projection
+        let shift = Vec3 {
+            x: (to.x - from.x) / width.get() as f32 * 2.0,
+            y: -(to.y - from.y) / height.get() as f32 * 2.0,
+            z: 0.0,
+        };
+
+        Self {
+            view,
+            projection: Mat4::translation_3d(shift) * projection,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::num::NonZeroU32;
+
+    use crate::math::FrustumPlanes;
+
+    fn size() -> Size {
+        Size {width: NonZeroU32::new(100).unwrap(), height: NonZeroU32::new(50).unwrap()}
+    }
+
+    fn camera() -> Camera {
+        Camera {
+            view: Mat4::look_at_rh(Vec3::new(0.0, 0.0, 10.0), Vec3::zero(), Vec3::up()),
+            projection: Mat4::orthographic_rh_no(FrustumPlanes {
+                left: -2.0,
+                right: 2.0,
+                bottom: -1.0,
+                top: 1.0,
+                near: 0.1,
+                far: 100.0,
+            }),
+        }
+    }
+
+    #[test]
+    fn projects_and_shifts_pivot() {
+        let camera = camera();
+
+        // The origin is at the center of the image and the bottom edge is at y = -1
+        assert_eq!(camera.project_to_pixels(Vec3::zero(), size()), Vec2::new(50.0, 25.0));
+        assert_eq!(camera.project_to_pixels(Vec3::new(-2.0, -1.0, 0.0), size()), Vec2::new(0.0, 50.0));
+
+        let shifted = camera.shifted(Vec2::new(50.0, 25.0), Vec2::new(40.0, 45.0), size());
+        let moved = shifted.project_to_pixels(Vec3::zero(), size());
+        assert!((moved - Vec2::new(40.0, 45.0)).magnitude() < 1e-4);
+    }
+}
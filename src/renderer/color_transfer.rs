@@ -0,0 +1,69 @@
+use crate::math::Rgb;
+
+/// The transfer function used to encode the linear colors produced by lighting into the colors
+/// stored in an image
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ColorTransfer {
+    /// The sRGB transfer function, expected by most image viewers and editors
+    #[default]
+    Srgb,
+    /// Colors are stored without any encoding
+    Linear,
+    /// Colors are raised to the power of 1/gamma
+    Gamma(f32),
+}
+
+impl ColorTransfer {
+    /// Encodes a linear color component (between 0.0 and 1.0) using this transfer function
+    pub fn encode(self, value: f32) -> f32 {
+        use ColorTransfer::*;
+        match self {
+            // Source: https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+            Srgb => if value <= 0.0031308 {
+                value * 12.92
+            } else {
+                1.055 * value.powf(1.0 / 2.4) - 0.055
+            },
+            Linear => value,
+            Gamma(gamma) => value.powf(1.0 / gamma),
+        }
+    }
+
+    /// Decodes an encoded color component (between 0.0 and 1.0) back into a linear value
+    pub fn decode(self, value: f32) -> f32 {
+        use ColorTransfer::*;
+        match self {
+            // Source: https://en.wikipedia.org/wiki/SRGB#The_reverse_transformation
+            Srgb => if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            },
+            Linear => value,
+            Gamma(gamma) => value.powf(gamma),
+        }
+    }
+
+    pub fn encode_rgb(self, color: Rgb) -> Rgb {
+        color.map(|value| self.encode(value))
+    }
+
+    pub fn decode_rgb(self, color: Rgb) -> Rgb {
+        color.map(|value| self.decode(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_reverses_encode() {
+        for &transfer in &[ColorTransfer::Srgb, ColorTransfer::Linear, ColorTransfer::Gamma(2.2)] {
+            for &value in &[0.0, 0.001, 0.2, 0.5, 1.0] {
+                let decoded = transfer.decode(transfer.encode(value));
+                assert!((decoded - value).abs() < 1e-5, "{:?} did not round trip {}", transfer, value);
+            }
+        }
+    }
+}
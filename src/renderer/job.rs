@@ -4,7 +4,9 @@ use image::RgbaImage;
 
 use super::{
     RenderNode,
+    AlphaOutput,
     ThreadRenderContext,
+    PendingImage,
     DrawLayoutError,
     layout::LayoutNode,
 };
@@ -16,17 +18,25 @@ pub struct RenderJob {
     pub scale: NonZeroU32,
     /// The root node of the tree that describes the image to render
     pub root: RenderNode,
+    /// How the alpha channel of the generated image is written
+    pub alpha: AlphaOutput,
 }
 
 impl RenderJob {
     pub fn execute(self, ctx: &mut ThreadRenderContext) -> Result<RgbaImage, DrawLayoutError> {
-        let Self {scale, root} = self;
+        let image = self.submit(ctx)?;
+        Ok(image.wait()?)
+    }
+
+    /// Draws the image on the GPU and starts reading it back without waiting for it to be ready
+    pub fn submit(self, ctx: &mut ThreadRenderContext) -> Result<PendingImage, DrawLayoutError> {
+        let Self {scale, root, alpha} = self;
 
         let layout = LayoutNode::from_render_node(root)?;
 
         let image = ctx.draw(layout)?;
-        let image = ctx.scale(&image, scale)?;
+        let image = ctx.scale(image, scale)?;
 
-        Ok(image)
+        Ok(ctx.read(&image).with_alpha(alpha))
     }
 }
@@ -1,7 +1,8 @@
 use std::sync::Arc;
+use std::cmp::Ordering;
 
 use crate::scene::LightType;
-use crate::math::Mat4;
+use crate::math::{Mat4, Vec3, Rgb, Decompose, Transforms};
 
 #[derive(Debug, Clone)]
 pub struct Light {
@@ -10,3 +11,95 @@ pub struct Light {
     /// The world transform of the light
     pub world_transform: Mat4,
 }
+
+impl Light {
+    /// Returns an estimate of how much this light contributes to the lighting at the given point
+    /// in world coordinates
+    ///
+    /// This only needs to be good enough to compare lights with each other.
+    pub fn influence_at(&self, point: Vec3) -> f32 {
+        let Self {data, world_transform} = self;
+        let Transforms {scale: _, rotation, translation: position} = world_transform.decompose();
+        let direction = rotation * Vec3 {x: 0.0, y: 0.0, z: -1.0};
+
+        // The perceived brightness of the light color
+        fn luminance(color: Rgb) -> f32 {
+            0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b
+        }
+
+        // Same as the range_attenuation function in the cel shader
+        fn range_attenuation(distance: f32, range: Option<f32>) -> f32 {
+            // Avoids dividing by zero when the point is at the position of the light
+            let distance = distance.max(0.001);
+            let window = match range {
+                Some(range) => (1.0 - (distance / range).powi(4)).clamp(0.0, 1.0),
+                None => 1.0,
+            };
+            window / (distance * distance)
+        }
+
+        use LightType::*;
+        match &**data {
+            &Point {name: _, color, intensity, range} => {
+                let distance = (point - position).magnitude();
+                luminance(color) * intensity * range_attenuation(distance, range)
+            },
+
+            &Directional {name: _, color, intensity} => luminance(color) * intensity,
+
+            &Spot {name: _, color, intensity, range, inner_cone_angle: _, outer_cone_angle} => {
+                let to_point = point - position;
+                let distance = to_point.magnitude();
+                // Points outside of the cone are not lit at all
+                let cos_angle = direction.dot(to_point / distance.max(0.001));
+                if cos_angle < outer_cone_angle.get_radians().cos() {
+                    return 0.0;
+                }
+
+                luminance(color) * intensity * range_attenuation(distance, range)
+            },
+        }
+    }
+}
+
+/// Returns the indexes of at most `max_lights` lights that contribute the most to the lighting at
+/// the given point in world coordinates. The indexes are returned in ascending order.
+pub fn most_influential_lights(lights: &[Arc<Light>], max_lights: usize, point: Vec3) -> Vec<usize> {
+    let mut indexes: Vec<usize> = (0..lights.len()).collect();
+    if lights.len() <= max_lights {
+        return indexes;
+    }
+
+    let influences: Vec<f32> = lights.iter().map(|light| light.influence_at(point)).collect();
+    // Sort from most to least influential
+    indexes.sort_by(|&a, &b| influences[b].partial_cmp(&influences[a]).unwrap_or(Ordering::Equal));
+    indexes.truncate(max_lights);
+    indexes.sort_unstable();
+
+    indexes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point_light(position: Vec3, intensity: f32) -> Arc<Light> {
+        Arc::new(Light {
+            data: Arc::new(LightType::Point {name: None, color: Rgb::white(), intensity, range: None}),
+            world_transform: Mat4::translation_3d(position),
+        })
+    }
+
+    #[test]
+    fn closest_lights_are_most_influential() {
+        let lights = vec![
+            point_light(Vec3::new(10.0, 0.0, 0.0), 1.0),
+            point_light(Vec3::new(1.0, 0.0, 0.0), 1.0),
+            point_light(Vec3::new(5.0, 0.0, 0.0), 1.0),
+            point_light(Vec3::new(2.0, 0.0, 0.0), 1.0),
+        ];
+
+        assert_eq!(most_influential_lights(&lights, 2, Vec3::zero()), vec![1, 3]);
+        assert_eq!(most_influential_lights(&lights, 10, Vec3::zero()), vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use glium::Texture2d;
+use glium::texture::{UncompressedFloatFormat, MipmapsOption, TextureCreationError};
+
+use crate::math::{Vec3, Decompose, Transforms};
+use crate::scene::LightType;
+use crate::renderer::{Display, Light};
+
+/// Stores the lights as a texture so that any number of lights can be used in the shader
+///
+/// This works the same way as `JointMatrixTexture`. Each x coordinate is a light and each of the
+/// y coordinates 0 through 3 is one 4D value describing that light. The layout must match the
+/// `light_at` function in the cel shader.
+#[derive(Debug)]
+pub struct LightTexture {
+    texture: Texture2d,
+    len: usize,
+}
+
+impl LightTexture {
+    pub fn new<'a>(
+        display: &Display,
+        lights: impl Iterator<Item=&'a Arc<Light>>,
+    ) -> Result<Self, TextureCreationError> {
+        let mut rows = vec![Vec::new(), Vec::new(), Vec::new(), Vec::new()];
+        for light in lights {
+            for (row, value) in rows.iter_mut().zip(light_texels(light).iter()) {
+                row.push(*value);
+            }
+        }
+
+        let len = rows[0].len();
+        // Textures cannot be empty, so an unused texel is added when there are no lights
+        if len == 0 {
+            for row in &mut rows {
+                row.push((0.0, 0.0, 0.0, 0.0));
+            }
+        }
+
+        let texture = Texture2d::with_format(display, rows, UncompressedFloatFormat::F32F32F32F32, MipmapsOption::NoMipmap)?;
+
+        Ok(Self {texture, len})
+    }
+
+    /// Returns the number of lights in the texture
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no lights in the texture
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_texture(&self) -> &Texture2d {
+        &self.texture
+    }
+}
+
+/// Returns the 4 texels that describe the given light
+///
+/// * 0 - the position of the light (w = 1.0) or the direction it travels in (w = 0.0)
+/// * 1 - the color of the light multiplied by its intensity, and its range (0.0 if infinite)
+/// * 2 - the direction of the spot light cone, and light_angle_scale
+/// * 3 - light_angle_offset (the other components are unused)
+fn light_texels(light: &Light) -> [(f32, f32, f32, f32); 4] {
+    let Light {data, world_transform} = light;
+
+    // scale is ignored by all the different light types
+    let Transforms {scale: _, rotation, translation: pos} = world_transform.decompose();
+    let direction = rotation * Vec3 {x: 0.0, y: 0.0, z: -1.0};
+
+    use LightType::*;
+    match &**data {
+        &Point {name: _, color, intensity, range} => {
+            let color = color * intensity;
+            [
+                (pos.x, pos.y, pos.z, 1.0),
+                (color.r, color.g, color.b, range.unwrap_or(0.0)),
+                (0.0, 0.0, 0.0, 0.0),
+                (0.0, 0.0, 0.0, 0.0),
+            ]
+        },
+
+        &Directional {name: _, color, intensity} => {
+            let color = color * intensity;
+            [
+                (direction.x, direction.y, direction.z, 0.0),
+                (color.r, color.g, color.b, 0.0),
+                (0.0, 0.0, 0.0, 0.0),
+                (0.0, 0.0, 0.0, 0.0),
+            ]
+        },
+
+        &Spot {name: _, color, intensity, range, inner_cone_angle, outer_cone_angle} => {
+            // cos() expects a value in radians
+            let inner_cone_angle = inner_cone_angle.get_radians();
+            let outer_cone_angle = outer_cone_angle.get_radians();
+
+            let light_angle_scale = 1.0 / 0.001f32.max(inner_cone_angle.cos() - outer_cone_angle.cos());
+            let light_angle_offset = -outer_cone_angle.cos() * light_angle_scale;
+
+            let color = color * intensity;
+            [
+                (pos.x, pos.y, pos.z, 1.0),
+                (color.r, color.g, color.b, range.unwrap_or(0.0)),
+                (direction.x, direction.y, direction.z, light_angle_scale),
+                (light_angle_offset, 0.0, 0.0, 0.0),
+            ]
+        },
+    }
+}
@@ -0,0 +1,39 @@
+use glium::texture::{RawImage2d, pixel_buffer::PixelBuffer};
+use image::{RgbaImage, imageops::flip_vertical_in_place};
+
+use super::AlphaOutput;
+
+/// An image that is being read back from the GPU
+///
+/// The transfer is started when this is created and happens asynchronously. Only `wait` blocks
+/// until the data is available, so any other work (e.g. rendering the next image) can be done in
+/// between without waiting for the GPU.
+pub struct PendingImage {
+    buffer: PixelBuffer<(u8, u8, u8, u8)>,
+    alpha: AlphaOutput,
+}
+
+impl PendingImage {
+    pub(in super) fn new(buffer: PixelBuffer<(u8, u8, u8, u8)>) -> Self {
+        Self {buffer, alpha: AlphaOutput::default()}
+    }
+
+    /// Applies the given alpha settings to the image once it has been read
+    pub fn with_alpha(self, alpha: AlphaOutput) -> Self {
+        Self {alpha, ..self}
+    }
+
+    /// Waits for the transfer to finish and returns the image that was read
+    pub fn wait(self) -> Result<RgbaImage, glium::buffer::ReadError> {
+        let Self {buffer, alpha} = self;
+
+        let image: RawImage2d<u8> = buffer.read_as_texture_2d()?;
+        let mut image = RgbaImage::from_raw(image.width, image.height, image.data.into_owned())
+            .expect("bug: image data buffer did not match expected size for width and height");
+        // OpenGL images start at the bottom left corner, but our images start at the top left
+        flip_vertical_in_place(&mut image);
+        alpha.apply(&mut image);
+
+        Ok(image)
+    }
+}
@@ -1,11 +1,12 @@
 use std::num::NonZeroU32;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
-use crate::math::{Rgb, Rgba};
+use crate::math::{Mat4, Vec2, Vec3, Vec4, Rgb, Rgba};
 
-use crate::query3d::{GeometryQuery, LightQuery, CameraQuery, File, QueryError, QueryBackend};
+use crate::query3d::{GeometryQuery, LightQuery, CameraQuery, NodeQuery, File, QueryError, QueryBackend};
 
-use super::{Camera, Light};
+use super::{Camera, Light, ColorTransfer};
 
 /// An image that will be rendered using the given information
 #[derive(Debug, Clone)]
@@ -18,12 +19,25 @@ pub struct RenderedImage {
     pub camera: RenderCamera,
     /// The lights to use to light the rendered scene
     pub lights: RenderLights,
+    /// The maximum number of lights used to light each piece of geometry, or None to use every
+    /// light. Only the lights that contribute the most at the origin of the geometry are used,
+    /// which is a poor estimate of where a skinned mesh is since its joints can move it far away
+    /// from its origin.
+    pub max_lights: Option<NonZeroU32>,
     /// The ambient light in the scene
     pub ambient_light: Rgb,
-    /// The geometry to draw in the rendered image
-    pub geometry: FileQuery<GeometryQuery>,
+    /// The geometry to draw in the rendered image. All of the sources are depth tested together,
+    /// so they can overlap each other.
+    pub geometry: Vec<GeometrySource>,
     /// The outline to use when drawing the geometry
     pub outline: Outline,
+    /// The shadows cast by the lights, or None if no shadows should be drawn
+    pub shadows: Option<Shadows>,
+    /// The rim lights and specular highlights to draw on each material
+    pub highlights: MaterialHighlights,
+    /// The transfer function used to encode the lit colors. The background and outline colors
+    /// are drawn as is, so they should already use this encoding.
+    pub color_transfer: ColorTransfer,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,6 +66,44 @@ impl Size {
     }
 }
 
+/// Geometry from a model file, moved into place in the rendered image
+#[derive(Debug, Clone)]
+pub struct GeometrySource {
+    pub geometry: FileQuery<GeometryQuery>,
+    /// A transform applied to all of the geometry on top of its own world transforms (e.g. to
+    /// place a model next to another model)
+    pub transform: Mat4,
+    /// A node whose world transform is applied on top of `transform`, so that the geometry moves
+    /// along with the node (e.g. a sword in a hand), or None if the geometry is not attached
+    pub attach: Option<FileQuery<NodeQuery>>,
+}
+
+impl From<FileQuery<GeometryQuery>> for GeometrySource {
+    fn from(geometry: FileQuery<GeometryQuery>) -> Self {
+        Self {
+            geometry,
+            transform: Mat4::identity(),
+            attach: None,
+        }
+    }
+}
+
+impl GeometrySource {
+    /// Returns the transform to apply to all of the geometry, including the transform of the node
+    /// it is attached to
+    pub fn fetch_transform(&self) -> Result<Mat4, QueryError> {
+        let Self {geometry: _, transform, attach} = self;
+
+        match attach {
+            Some(FileQuery {query, file}) => {
+                let file = file.lock().expect("bug: file lock was poisoned");
+                Ok(file.query_node_transform(query)? * *transform)
+            },
+            None => Ok(*transform),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Outline {
     /// The outline thickness to use when drawing the generated image
@@ -62,10 +114,92 @@ pub struct Outline {
     pub color: Rgba,
 }
 
+/// The highlights to draw on every material, with overrides for specific materials
+#[derive(Debug, Clone, Default)]
+pub struct MaterialHighlights {
+    /// The highlights used for any material that is not in `materials`
+    pub default: Highlights,
+    /// The highlights used for materials with the given names
+    pub materials: HashMap<String, Highlights>,
+}
+
+impl MaterialHighlights {
+    /// Returns the highlights to use for the material with the given name
+    pub fn get(&self, material_name: Option<&str>) -> &Highlights {
+        material_name.and_then(|name| self.materials.get(name)).unwrap_or(&self.default)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Highlights {
+    /// The rim light to draw, or None if there should be no rim light
+    pub rim_light: Option<RimLight>,
+    /// The specular highlight to draw, or None if there should be no specular highlight
+    pub specular: Option<Specular>,
+}
+
+/// A hard edged band of light along the silhouette of a model
+#[derive(Debug, Clone)]
+pub struct RimLight {
+    /// The color of the rim light
+    pub color: Rgb,
+    /// The width of the rim, between 0.0 (no rim) and 1.0 (the entire surface)
+    pub width: f32,
+    /// The minimum amount of light that a surface must receive to have a rim
+    pub threshold: f32,
+}
+
+/// A single step specular highlight
+#[derive(Debug, Clone)]
+pub struct Specular {
+    /// The color of the highlight, or None to use white for non-metals and the diffuse color for
+    /// metals
+    pub color: Option<Rgb>,
+    /// The specular exponent, or None to use a value based on the roughness of the material.
+    /// Higher values produce smaller highlights.
+    pub shininess: Option<f32>,
+    /// The minimum specular intensity drawn as part of the highlight
+    pub threshold: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Shadows {
+    /// The color that the darkest band of the cel shading is multiplied by
+    pub tint: Rgb,
+    /// The width, height, and depth of the region around the origin that can have shadows
+    ///
+    /// The value must be greater than zero.
+    pub extent: f32,
+    /// The width and height of the shadow map texture
+    pub map_size: NonZeroU32,
+    /// The shadow drawn on an invisible ground plane, or None if no drop shadow should be drawn
+    pub drop_shadow: Option<DropShadow>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DropShadow {
+    /// The kind of drop shadow to draw
+    pub style: DropShadowStyle,
+    /// The position of the invisible ground plane along the world y-axis
+    pub ground_height: f32,
+    /// The color of the drop shadow (the alpha component controls its opacity)
+    pub color: Rgba,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DropShadowStyle {
+    /// The shadow cast onto the ground by the light used for the shadow map
+    Projected,
+    /// A circle on the ground, centered below the origin
+    Blob {radius: f32},
+}
+
 #[derive(Debug, Clone)]
 pub enum RenderCamera {
     Camera(Arc<Camera>),
     Query(FileQuery<CameraQuery>),
+    Follow(Box<FollowCamera>),
+    Pivot(Box<PivotCamera>),
 }
 
 impl RenderCamera {
@@ -77,6 +211,99 @@ impl RenderCamera {
                 let mut file = file.lock().expect("bug: file lock was poisoned");
                 file.query_camera(query)
             },
+            Follow(follow) => follow.fetch_camera(),
+            Pivot(pivot) => pivot.fetch_camera(),
+        }
+    }
+}
+
+/// A camera that moves by the same amount that a node has moved from its original position
+#[derive(Debug, Clone)]
+pub struct FollowCamera {
+    /// The camera to move
+    pub camera: RenderCamera,
+    /// The node to follow, along with the animation that moves it
+    pub target: FileQuery<NodeQuery>,
+    /// True if the camera should follow the node along the world x-axis
+    pub x: bool,
+    /// True if the camera should follow the node along the world y-axis
+    pub y: bool,
+    /// True if the camera should follow the node along the world z-axis
+    pub z: bool,
+}
+
+impl FollowCamera {
+    fn fetch_camera(&self) -> Result<Arc<Camera>, QueryError> {
+        let Self {camera, target: FileQuery {query, file}, x, y, z} = self;
+
+        let camera = camera.fetch_camera()?;
+        let (animated, original) = {
+            let file = file.lock().expect("bug: file lock was poisoned");
+            let original_query = NodeQuery {animation: None, ..query.clone()};
+            (file.query_node_transform(query)?, file.query_node_transform(&original_query)?)
+        };
+
+        let moved = animated.cols.w - original.cols.w;
+        let offset = Vec3 {
+            x: if *x { moved.x } else { 0.0 },
+            y: if *y { moved.y } else { 0.0 },
+            z: if *z { moved.z } else { 0.0 },
+        };
+
+        // Moving the camera by the offset is the same as moving the world by the opposite offset
+        Ok(Arc::new(Camera {
+            view: camera.view * Mat4::translation_3d(-offset),
+            projection: camera.projection,
+        }))
+    }
+}
+
+/// A camera shifted so that a point (e.g. the feet of a character) is always drawn at the same
+/// pixel position
+#[derive(Debug, Clone)]
+pub struct PivotCamera {
+    /// The camera to shift
+    pub camera: RenderCamera,
+    /// The point to draw at `align_to`
+    pub pivot: NodePoint,
+    /// The pixel position to draw the point at, measured from the top left corner of the image
+    pub align_to: Vec2,
+    /// The size of the image drawn with the camera
+    pub size: Size,
+}
+
+impl PivotCamera {
+    fn fetch_camera(&self) -> Result<Arc<Camera>, QueryError> {
+        let &Self {ref camera, ref pivot, align_to, size} = self;
+
+        let camera = camera.fetch_camera()?;
+        let pixel = camera.project_to_pixels(pivot.fetch_position()?, size);
+        Ok(Arc::new(camera.shifted(pixel, align_to, size)))
+    }
+}
+
+/// A point that moves along with a node of a model file
+#[derive(Debug, Clone)]
+pub struct NodePoint {
+    /// The position of the point in the coordinates of the node, or in world coordinates if the
+    /// point is not attached to a node
+    pub offset: Vec3,
+    /// The node that the point moves along with, or None if the point does not move
+    pub attach: Option<FileQuery<NodeQuery>>,
+}
+
+impl NodePoint {
+    /// Returns the position of the point in world coordinates
+    pub fn fetch_position(&self) -> Result<Vec3, QueryError> {
+        let &Self {offset, ref attach} = self;
+
+        match attach {
+            Some(FileQuery {query, file}) => {
+                let file = file.lock().expect("bug: file lock was poisoned");
+                let transform = file.query_node_transform(query)?;
+                Ok(Vec3::from(transform * Vec4::from_point(offset)))
+            },
+            None => Ok(offset),
         }
     }
 }
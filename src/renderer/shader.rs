@@ -1,6 +1,8 @@
 mod nested_uniforms;
-mod light_uniform;
 mod material_uniform;
+mod shadow_map_uniform;
 
 pub mod cel;
 pub mod outline;
+pub mod shadow;
+pub mod ground;
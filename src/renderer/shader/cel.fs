@@ -58,17 +58,59 @@ struct Material {
     vec4 diffuse_color;
     bool use_texture;
     sampler2D tex;
+
+    // The light emitted by the material, added on top of any lighting
+    vec3 emissive_color;
+    bool use_emissive_texture;
+    sampler2D emissive_tex;
+    // If true, all lighting is ignored and the diffuse color is drawn as is
+    bool unlit;
+
+    // A hard edged band of light along the silhouette of the model
+    bool use_rim_light;
+    vec3 rim_color;
+    // Between 0.0 (no rim) and 1.0 (the entire surface)
+    float rim_width;
+    // The minimum amount of light that a surface must receive to have a rim
+    float rim_threshold;
+
+    // A single step Blinn-Phong specular highlight
+    bool use_specular;
+    vec3 specular_color;
+    // The specular exponent, higher values produce smaller highlights
+    float shininess;
+    // The minimum specular intensity drawn as part of the highlight
+    float specular_threshold;
 };
 
+// If w = 1.0, the position of the camera in world coordinates
+// If w = 0.0, the **normalized** direction towards the camera
+uniform vec4 camera_position;
+
 // Light parameters
-#define MAX_LIGHTS 10
 uniform int num_lights;
-uniform Light lights[MAX_LIGHTS];
+// Each x coordinate is one light (see light_at)
+uniform sampler2D lights;
 uniform vec3 ambient_light;
+// The color that the darkest band of the cel shading is multiplied by
+uniform vec3 shadow_tint;
+
+// Shadow map parameters
+uniform bool use_shadow_map;
+uniform sampler2D shadow_map;
+// Transforms world coordinates into the clip space of the light
+uniform mat4 light_space;
+// The index of the light that casts shadows
+uniform int shadow_light;
 
 // Material data
 uniform Material material;
 
+// The transfer function used to encode the final color (see encode_output)
+uniform int output_transfer;
+// The gamma used when output_transfer is 2
+uniform float output_gamma;
+
 // This is assumed to be normalized
 in vec3 v_normal;
 in vec3 v_position;
@@ -76,16 +118,65 @@ in vec2 v_tex_coord;
 
 out vec4 frag_color;
 
+// Unpacks the light at the given index of the lights texture
+//
+// The layout of the texture must match LightTexture
+Light light_at(int i) {
+    vec4 position = texelFetch(lights, ivec2(i, 0), 0);
+    vec4 color_range = texelFetch(lights, ivec2(i, 1), 0);
+    vec4 cone = texelFetch(lights, ivec2(i, 2), 0);
+    vec4 angle_offset = texelFetch(lights, ivec2(i, 3), 0);
+
+    return Light(position, color_range.rgb, color_range.a, cone.xyz, cone.w, angle_offset.x);
+}
+
+// Converts a color from sRGB to linear space
+//
+// Source: https://en.wikipedia.org/wiki/SRGB#The_reverse_transformation
+vec3 srgb_to_linear(vec3 color) {
+    vec3 low = color / 12.92;
+    vec3 high = pow((color + 0.055) / 1.055, vec3(2.4));
+    return mix(high, low, vec3(lessThanEqual(color, vec3(0.04045))));
+}
+
+// Encodes a color in linear space using the configured output transfer function
+//
+// This must match ColorTransfer::encode
+vec3 encode_output(vec3 color) {
+    color = clamp(color, 0.0, 1.0);
+    if (output_transfer == 1) {
+        // sRGB
+        // Source: https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+        vec3 low = color * 12.92;
+        vec3 high = 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055;
+        return mix(high, low, vec3(lessThanEqual(color, vec3(0.0031308))));
+    } else if (output_transfer == 2) {
+        return pow(color, vec3(1.0 / output_gamma));
+    } else {
+        // Linear
+        return color;
+    }
+}
+
 vec3 mat_diffuse_color() {
     if (material.use_texture) {
+        // Textures are stored in sRGB, but lighting is done in linear space
         // Discards the texture alpha component
-        return vec3(texture(material.tex, v_tex_coord));
+        return srgb_to_linear(vec3(texture(material.tex, v_tex_coord)));
     } else {
         // Discards the material alpha component
         return vec3(material.diffuse_color);
     }
 }
 
+vec3 mat_emissive_color() {
+    if (material.use_emissive_texture) {
+        return material.emissive_color * srgb_to_linear(vec3(texture(material.emissive_tex, v_tex_coord)));
+    } else {
+        return material.emissive_color;
+    }
+}
+
 // https://github.com/KhronosGroup/glTF-Sample-Viewer/blob/a18868cfe652bab4c084c751c80a6cfb55ae0f2f/src/shaders/metallic-roughness.frag#L199-L208
 float range_attenuation(float distance, float range) {
     if (range <= 0.0) {
@@ -96,10 +187,38 @@ float range_attenuation(float distance, float range) {
     return max(min(1.0 - pow(distance / range, 4), 1.0), 0.0) / pow(distance, 2);
 }
 
+// Returns the normalized direction from the given world position to the camera
+vec3 surface_to_camera(vec3 position) {
+    if (camera_position.w == 0.0) {
+        // Orthographic camera
+        return camera_position.xyz;
+    } else {
+        // Perspective camera
+        return normalize(camera_position.xyz - position);
+    }
+}
+
+// Returns true if the given world position is hidden from the light that
+// casts shadows
+bool in_shadow(vec3 position, vec3 normal, vec3 surface_to_light) {
+    vec4 light_position = light_space * vec4(position, 1.0);
+    // Map from clip space to the [0, 1] range of the shadow map
+    vec3 coords = light_position.xyz / light_position.w * 0.5 + 0.5;
+    // Positions outside of the shadow map are never in shadow
+    if (coords.x < 0.0 || coords.x > 1.0 || coords.y < 0.0 || coords.y > 1.0 || coords.z > 1.0) {
+        return false;
+    }
+
+    // Surfaces at a steep angle to the light need a larger bias to avoid
+    // shadowing themselves ("shadow acne")
+    float bias = max(0.005 * (1.0 - dot(normal, surface_to_light)), 0.0005);
+    return coords.z - bias > texture(shadow_map, coords.xy).r;
+}
+
 // Uses the lighting model to compute the color of a point on a surface.
 //
 // Both position and normal should be in the world coordinate system.
-vec3 apply_light(Light light, vec3 position, vec3 normal) {
+vec3 apply_light(int index, Light light, vec3 position, vec3 normal) {
     // The lighting model implemented here is designed around supporting the
     // glTF punctual lights extension. The calculations performed conform to
     // that spec. Some features found in other lighting implementations may be
@@ -141,6 +260,10 @@ vec3 apply_light(Light light, vec3 position, vec3 normal) {
     // Calculate diffuse light amount
     // max() is used to bottom out at zero if the dot product is negative
     float diffuse_intensity = max(dot(v_normal, surface_to_light), 0.0);
+    // Shadowed surfaces receive no light, putting them in the darkest band
+    if (use_shadow_map && index == shadow_light && in_shadow(position, normal, surface_to_light)) {
+        diffuse_intensity = 0.0;
+    }
 
     // Calculate what would normally be the final color, including texturing and
     // diffuse lighting
@@ -163,23 +286,51 @@ vec3 apply_light(Light light, vec3 position, vec3 normal) {
        color *= 0.35;
 
     } else {
-       color *= 0.1;
+       color *= shadow_tint;
+    }
+
+    // The highlights are only drawn on the parts of the surface that the light reaches
+    if (diffuse_intensity > 0.0) {
+        vec3 to_camera = surface_to_camera(position);
+
+        if (material.use_rim_light) {
+            // The rim is where the surface turns away from the camera
+            float rim = 1.0 - max(dot(v_normal, to_camera), 0.0);
+            if (rim > 1.0 - material.rim_width && light_intensity > material.rim_threshold) {
+                color += material.rim_color * light.color;
+            }
+        }
+
+        if (material.use_specular) {
+            // Blinn-Phong specular intensity, discretised into a single step
+            vec3 halfway = normalize(surface_to_light + to_camera);
+            float specular_intensity = pow(max(dot(v_normal, halfway), 0.0), material.shininess) * attenuation;
+            if (specular_intensity > material.specular_threshold) {
+                color += material.specular_color * light.color;
+            }
+        }
     }
 
     return color;
 }
 
 void main() {
-    vec3 final_color = mat_diffuse_color() * ambient_light;
-    for (int i = 0; i < num_lights; i++) {
-        Light light = lights[i];
-        final_color += apply_light(light, v_position, v_normal);
+    vec3 final_color;
+    if (material.unlit) {
+        final_color = mat_diffuse_color();
+    } else {
+        final_color = mat_diffuse_color() * ambient_light;
+        for (int i = 0; i < num_lights; i++) {
+            Light light = light_at(i);
+            final_color += apply_light(i, light, v_position, v_normal);
+        }
     }
+    // Emitted light is not affected by the lights in the scene, so it is never cel shaded
+    final_color += mat_emissive_color();
 
-    // Gamma correction -- apply at the very end
+    // Lighting is done in linear space, so the color is only encoded at the very end
     // Technique from: https://learnopengl.com/Advanced-Lighting/Gamma-Correction
-    float gamma = 2.2;
-    final_color = pow(final_color, vec3(1.0/gamma));
+    final_color = encode_output(final_color);
 
     frag_color = vec4(final_color, 1.0);
 }
@@ -1,27 +1,29 @@
-use std::sync::Arc;
-
 use glium::uniforms::{Uniforms, UniformValue};
 
-use crate::math::{Mat4, Rgb};
-use crate::renderer::{Light, JointMatrixTexture, ShaderMaterial};
+use crate::math::{Mat4, Vec4, Rgb};
+use crate::renderer::{LightTexture, JointMatrixTexture, ShaderMaterial, ShadowMap, Highlights, ColorTransfer};
 
 use super::nested_uniforms::NestedUniforms;
-use super::light_uniform::LightUniform;
 use super::material_uniform::MaterialUniform;
-
-/// The maximum supported number of lights
-///
-/// This value must match the corresponding value in the cel shaders
-const MAX_LIGHTS: usize = 10;
+use super::shadow_map_uniform::ShadowMapUniform;
 
 pub struct CelUniforms<'a> {
     pub mvp: Mat4,
     pub model_transform: Mat4,
     pub model_inverse_transpose: Mat4,
     pub joint_matrices: &'a JointMatrixTexture,
-    pub lights: &'a [Arc<Light>],
+    /// If w = 1.0, the position of the camera in world coordinates
+    /// If w = 0.0, the direction towards the camera (for orthographic cameras)
+    pub camera_position: Vec4,
+    pub lights: &'a LightTexture,
+    /// The index in `lights` of the light that casts the shadows in `shadow_map`, if any
+    pub shadow_light: Option<usize>,
     pub ambient_light: Rgb,
+    pub shadow_tint: Rgb,
+    pub shadow_map: Option<&'a ShadowMap>,
     pub material: &'a ShaderMaterial,
+    pub highlights: &'a Highlights,
+    pub color_transfer: ColorTransfer,
 }
 
 /// This struct must match the uniforms in the cel shaders
@@ -30,10 +32,16 @@ pub struct Cel<'a> {
     model_transform: UniformValue<'static>,
     model_inverse_transpose: UniformValue<'static>,
     joint_matrices: UniformValue<'a>,
+    camera_position: UniformValue<'static>,
     num_lights: UniformValue<'static>,
-    lights: Vec<LightUniform>,
+    lights: UniformValue<'a>,
+    shadow_light: UniformValue<'static>,
     ambient_light: UniformValue<'static>,
+    shadow_tint: UniformValue<'static>,
+    shadow_map: ShadowMapUniform<'a>,
     material: MaterialUniform<'a>,
+    output_transfer: UniformValue<'static>,
+    output_gamma: UniformValue<'static>,
 }
 
 impl<'b> Uniforms for Cel<'b> {
@@ -43,22 +51,32 @@ impl<'b> Uniforms for Cel<'b> {
             model_transform,
             model_inverse_transpose,
             joint_matrices,
+            camera_position,
             num_lights,
             lights,
+            shadow_light,
             ambient_light,
+            shadow_tint,
+            shadow_map,
             material,
+            output_transfer,
+            output_gamma,
         } = self;
 
         visit("mvp", *mvp);
         visit("model_transform", *model_transform);
         visit("model_inverse_transpose", *model_inverse_transpose);
         visit("joint_matrices", *joint_matrices);
+        visit("camera_position", *camera_position);
         visit("num_lights", *num_lights);
-        for (i, light) in lights.iter().enumerate() {
-            light.visit_nested_index("lights", i, &mut visit);
-        }
+        visit("lights", *lights);
+        visit("shadow_light", *shadow_light);
         visit("ambient_light", *ambient_light);
+        visit("shadow_tint", *shadow_tint);
         material.visit_nested("material", &mut visit);
+        visit("output_transfer", *output_transfer);
+        visit("output_gamma", *output_gamma);
+        shadow_map.visit_values(visit);
     }
 }
 
@@ -69,25 +87,40 @@ impl<'a> From<CelUniforms<'a>> for Cel<'a> {
             model_transform,
             model_inverse_transpose,
             joint_matrices,
+            camera_position,
             lights,
+            shadow_light,
             ambient_light,
+            shadow_tint,
+            shadow_map,
             material,
+            highlights,
+            color_transfer,
         } = cel_uniforms;
 
-        assert!(lights.len() <= MAX_LIGHTS, "Only up to {} lights can be rendered at any given time", MAX_LIGHTS);
+        // These values must match the encode_output function in the cel shader
+        let (output_transfer, output_gamma) = match color_transfer {
+            ColorTransfer::Linear => (0, 1.0),
+            ColorTransfer::Srgb => (1, 1.0),
+            ColorTransfer::Gamma(gamma) => (2, gamma),
+        };
 
         Self {
             mvp: UniformValue::Mat4(mvp.into_col_arrays()),
             model_transform: UniformValue::Mat4(model_transform.into_col_arrays()),
             model_inverse_transpose: UniformValue::Mat4(model_inverse_transpose.into_col_arrays()),
             joint_matrices: UniformValue::Texture2d(joint_matrices.as_texture(), None),
+            camera_position: UniformValue::Vec4(camera_position.into_array()),
             num_lights: UniformValue::SignedInt(lights.len() as i32),
-            lights: lights.iter().map(|light| {
-                let Light {data, world_transform} = &**light;
-                LightUniform::new(data, *world_transform)
-            }).collect(),
+            lights: UniformValue::Texture2d(lights.as_texture(), None),
+            // No light has a negative index, so -1 means that no light casts shadows
+            shadow_light: UniformValue::SignedInt(shadow_light.map(|index| index as i32).unwrap_or(-1)),
             ambient_light: UniformValue::Vec3(ambient_light.into_array()),
-            material: MaterialUniform::new(material),
+            shadow_tint: UniformValue::Vec3(shadow_tint.into_array()),
+            shadow_map: ShadowMapUniform::new(shadow_map),
+            material: MaterialUniform::new(material, highlights),
+            output_transfer: UniformValue::SignedInt(output_transfer),
+            output_gamma: UniformValue::Float(output_gamma),
         }
     }
 }
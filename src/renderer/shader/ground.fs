@@ -0,0 +1,50 @@
+#version 140
+
+// The color of the drop shadow, the alpha component controls its opacity
+uniform vec4 shadow_color;
+// If true, a blob is drawn instead of the shadow projected by the light
+uniform bool blob;
+// The radius of the blob shadow
+uniform float blob_radius;
+
+// Shadow map parameters (only used for projected shadows)
+uniform bool use_shadow_map;
+uniform sampler2D shadow_map;
+// Transforms world coordinates into the clip space of the light
+uniform mat4 light_space;
+
+in vec3 v_position;
+
+out vec4 color;
+
+// Returns true if the given world position is hidden from the light that
+// casts shadows
+bool in_shadow(vec3 position) {
+    vec4 light_position = light_space * vec4(position, 1.0);
+    // Map from clip space to the [0, 1] range of the shadow map
+    vec3 coords = light_position.xyz / light_position.w * 0.5 + 0.5;
+    // Positions outside of the shadow map are never in shadow
+    if (coords.x < 0.0 || coords.x > 1.0 || coords.y < 0.0 || coords.y > 1.0 || coords.z > 1.0) {
+        return false;
+    }
+
+    // A small bias prevents the plane from shadowing itself
+    return coords.z - 0.002 > texture(shadow_map, coords.xy).r;
+}
+
+void main() {
+    bool shadowed;
+    if (blob) {
+        // A hard edged circle on the ground, centered below the origin
+        shadowed = length(v_position.xz) <= blob_radius;
+    } else {
+        shadowed = use_shadow_map && in_shadow(v_position);
+    }
+
+    // The ground itself is invisible, so only the shadow is drawn
+    if (!shadowed) {
+        discard;
+    }
+
+    color = shadow_color;
+}
@@ -0,0 +1,68 @@
+use glium::uniforms::{Uniforms, UniformValue};
+
+use crate::renderer::{ShadowMap, DropShadow, DropShadowStyle};
+use crate::math::Mat4;
+
+use super::shadow_map_uniform::ShadowMapUniform;
+
+pub struct GroundUniforms<'a> {
+    pub view_projection: Mat4,
+    pub extent: f32,
+    pub drop_shadow: &'a DropShadow,
+    pub shadow_map: Option<&'a ShadowMap>,
+}
+
+/// This struct must match the uniforms in the ground shaders
+pub struct Ground<'a> {
+    view_projection: UniformValue<'static>,
+    ground_height: UniformValue<'static>,
+    extent: UniformValue<'static>,
+    shadow_color: UniformValue<'static>,
+    blob: UniformValue<'static>,
+    blob_radius: UniformValue<'static>,
+    shadow_map: ShadowMapUniform<'a>,
+}
+
+impl<'b> Uniforms for Ground<'b> {
+    fn visit_values<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, mut visit: F) {
+        let Self {
+            view_projection,
+            ground_height,
+            extent,
+            shadow_color,
+            blob,
+            blob_radius,
+            shadow_map,
+        } = self;
+
+        visit("view_projection", *view_projection);
+        visit("ground_height", *ground_height);
+        visit("extent", *extent);
+        visit("shadow_color", *shadow_color);
+        visit("blob", *blob);
+        visit("blob_radius", *blob_radius);
+        shadow_map.visit_values(visit);
+    }
+}
+
+impl<'a> From<GroundUniforms<'a>> for Ground<'a> {
+    fn from(ground_uniforms: GroundUniforms<'a>) -> Self {
+        let GroundUniforms {view_projection, extent, drop_shadow, shadow_map} = ground_uniforms;
+        let &DropShadow {style, ground_height, color} = drop_shadow;
+
+        let (blob, blob_radius) = match style {
+            DropShadowStyle::Projected => (false, 0.0),
+            DropShadowStyle::Blob {radius} => (true, radius),
+        };
+
+        Self {
+            view_projection: UniformValue::Mat4(view_projection.into_col_arrays()),
+            ground_height: UniformValue::Float(ground_height),
+            extent: UniformValue::Float(extent),
+            shadow_color: UniformValue::Vec4(color.into_array()),
+            blob: UniformValue::Bool(blob),
+            blob_radius: UniformValue::Float(blob_radius),
+            shadow_map: ShadowMapUniform::new(shadow_map),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#version 140
+
+// The View Projection matrix (the ground plane is already in world coordinates)
+uniform mat4 view_projection;
+// The position of the ground plane along the world y-axis
+uniform float ground_height;
+// The width and height of the square ground plane, centered on the origin
+uniform float extent;
+
+// The position, in the world coordinate system
+out vec3 v_position;
+
+void main() {
+    // The corners of the ground plane are generated from the vertex index so
+    // that no vertex buffer is needed. The vertices are in triangle strip order.
+    float x = (gl_VertexID % 2 == 0) ? -0.5 : 0.5;
+    float z = (gl_VertexID < 2) ? -0.5 : 0.5;
+    v_position = vec3(x * extent, ground_height, z * extent);
+
+    gl_Position = view_projection * vec4(v_position, 1.0);
+}
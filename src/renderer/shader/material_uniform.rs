@@ -1,16 +1,25 @@
 use glium::uniforms::{Uniforms, UniformValue, SamplerBehavior};
 
-use crate::renderer::{ShaderMaterial, ShaderTexture};
+use crate::math::Rgb;
+use crate::scene::roughness_to_shininess;
+use crate::renderer::{ShaderMaterial, ShaderTexture, Highlights, RimLight, Specular};
 
 /// This struct must match the `Material` struct in our shaders
 pub struct MaterialUniform<'a> {
     diffuse_color: UniformValue<'static>,
     tex: Option<UniformValue<'a>>,
+    emissive_color: UniformValue<'static>,
+    emissive_tex: Option<UniformValue<'a>>,
+    unlit: UniformValue<'static>,
+    /// The color, width and threshold of the rim light
+    rim_light: Option<(UniformValue<'static>, UniformValue<'static>, UniformValue<'static>)>,
+    /// The color, shininess and threshold of the specular highlight
+    specular: Option<(UniformValue<'static>, UniformValue<'static>, UniformValue<'static>)>,
 }
 
 impl<'b> Uniforms for MaterialUniform<'b> {
     fn visit_values<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, mut visit: F) {
-        let &Self {diffuse_color, tex} = self;
+        let &Self {diffuse_color, tex, emissive_color, emissive_tex, unlit, rim_light, specular} = self;
         visit("diffuse_color", diffuse_color);
         if let Some(tex) = tex {
             visit("use_texture", UniformValue::Bool(true));
@@ -18,32 +27,100 @@ impl<'b> Uniforms for MaterialUniform<'b> {
         } else {
             visit("use_texture", UniformValue::Bool(false));
         }
+
+        visit("emissive_color", emissive_color);
+        if let Some(emissive_tex) = emissive_tex {
+            visit("use_emissive_texture", UniformValue::Bool(true));
+            visit("emissive_tex", emissive_tex);
+        } else {
+            visit("use_emissive_texture", UniformValue::Bool(false));
+        }
+        visit("unlit", unlit);
+
+        if let Some((color, width, threshold)) = rim_light {
+            visit("use_rim_light", UniformValue::Bool(true));
+            visit("rim_color", color);
+            visit("rim_width", width);
+            visit("rim_threshold", threshold);
+        } else {
+            visit("use_rim_light", UniformValue::Bool(false));
+        }
+
+        if let Some((color, shininess, threshold)) = specular {
+            visit("use_specular", UniformValue::Bool(true));
+            visit("specular_color", color);
+            visit("shininess", shininess);
+            visit("specular_threshold", threshold);
+        } else {
+            visit("use_specular", UniformValue::Bool(false));
+        }
     }
 }
 
 impl<'a> MaterialUniform<'a> {
-    pub fn new(material: &'a ShaderMaterial) -> Self {
-        let &ShaderMaterial {diffuse_color, ref texture} = material;
-
-        let tex = texture.as_ref().map(|texture| {
-            let &ShaderTexture {ref image, magnify_filter, minify_filter, wrap_s, wrap_t} = texture;
-
-            let mut behavior = SamplerBehavior::default();
-            if let Some(magnify_filter) = magnify_filter {
-                behavior.magnify_filter = magnify_filter;
-            }
-            if let Some(minify_filter) = minify_filter {
-                behavior.minify_filter = minify_filter;
-            }
-            behavior.wrap_function.0 = wrap_s;
-            behavior.wrap_function.1 = wrap_t;
-
-            UniformValue::Texture2d(image, Some(behavior))
+    pub fn new(material: &'a ShaderMaterial, highlights: &Highlights) -> Self {
+        let &ShaderMaterial {
+            name: _,
+            diffuse_color,
+            ref texture,
+            metallic,
+            roughness,
+            emissive_color,
+            ref emissive_texture,
+            unlit,
+        } = material;
+        let Highlights {rim_light, specular} = highlights;
+
+        let rim_light = rim_light.as_ref().map(|rim_light| {
+            let &RimLight {color, width, threshold} = rim_light;
+            (
+                UniformValue::Vec3(color.into_array()),
+                UniformValue::Float(width),
+                UniformValue::Float(threshold),
+            )
+        });
+
+        let specular = specular.as_ref().map(|specular| {
+            let &Specular {color, shininess, threshold} = specular;
+
+            // Metals tint their highlights with their own color
+            let color = color.unwrap_or_else(|| {
+                let metallic = metallic.clamp(0.0, 1.0);
+                Rgb::white() * (1.0 - metallic) + diffuse_color.rgb() * metallic
+            });
+            let shininess = shininess.unwrap_or_else(|| roughness_to_shininess(roughness));
+
+            (
+                UniformValue::Vec3(color.into_array()),
+                UniformValue::Float(shininess),
+                UniformValue::Float(threshold),
+            )
         });
 
         Self {
             diffuse_color: UniformValue::Vec4(diffuse_color.into_array()),
-            tex,
+            tex: texture.as_ref().map(texture_uniform),
+            emissive_color: UniformValue::Vec3(emissive_color.into_array()),
+            emissive_tex: emissive_texture.as_ref().map(texture_uniform),
+            unlit: UniformValue::Bool(unlit),
+            rim_light,
+            specular,
         }
     }
 }
+
+fn texture_uniform(texture: &ShaderTexture) -> UniformValue<'_> {
+    let &ShaderTexture {ref image, magnify_filter, minify_filter, wrap_s, wrap_t} = texture;
+
+    let mut behavior = SamplerBehavior::default();
+    if let Some(magnify_filter) = magnify_filter {
+        behavior.magnify_filter = magnify_filter;
+    }
+    if let Some(minify_filter) = minify_filter {
+        behavior.minify_filter = minify_filter;
+    }
+    behavior.wrap_function.0 = wrap_s;
+    behavior.wrap_function.1 = wrap_t;
+
+    UniformValue::Texture2d(image, Some(behavior))
+}
@@ -2,10 +2,9 @@ use glium::uniforms::{Uniforms, UniformValue};
 
 /// Nests a set of uniforms under a prefix followed by '.'
 ///
-/// Used to create normals with names like `light.color` or `lights[1].color`
+/// Used to create uniforms with names like `material.diffuse_color`
 pub trait NestedUniforms: Uniforms {
     fn visit_nested<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, prefix: &str, visit: F);
-    fn visit_nested_index<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, prefix: &str, index: usize, visit: F);
 }
 
 impl<T: Uniforms> NestedUniforms for T {
@@ -19,14 +18,4 @@ impl<T: Uniforms> NestedUniforms for T {
             visit(&name, value);
         });
     }
-
-    fn visit_nested_index<'a, F: FnMut(&str, UniformValue<'a>)>(
-        &'a self,
-        prefix: &str,
-        index: usize,
-        visit: F,
-    ) {
-        let prefix_index = format!("{}[{}]", prefix, index);
-        self.visit_nested(&prefix_index, visit);
-    }
 }
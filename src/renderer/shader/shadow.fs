@@ -0,0 +1,5 @@
+#version 140
+
+// Only the depth of each fragment is needed, and that is written automatically
+void main() {
+}
@@ -0,0 +1,35 @@
+use glium::uniforms::{Uniforms, UniformValue};
+
+use crate::renderer::JointMatrixTexture;
+use crate::math::Mat4;
+
+pub struct ShadowUniforms<'a> {
+    pub mvp: Mat4,
+    pub joint_matrices: &'a JointMatrixTexture,
+}
+
+/// This struct must match the uniforms in the shadow shaders
+pub struct Shadow<'a> {
+    mvp: UniformValue<'static>,
+    joint_matrices: UniformValue<'a>,
+}
+
+impl<'b> Uniforms for Shadow<'b> {
+    fn visit_values<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, mut visit: F) {
+        let &Self {mvp, joint_matrices} = self;
+
+        visit("mvp", mvp);
+        visit("joint_matrices", joint_matrices);
+    }
+}
+
+impl<'a> From<ShadowUniforms<'a>> for Shadow<'a> {
+    fn from(shadow_uniforms: ShadowUniforms<'a>) -> Self {
+        let ShadowUniforms {mvp, joint_matrices} = shadow_uniforms;
+
+        Self {
+            mvp: UniformValue::Mat4(mvp.into_col_arrays()),
+            joint_matrices: UniformValue::Texture2d(joint_matrices.as_texture(), None),
+        }
+    }
+}
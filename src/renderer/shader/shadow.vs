@@ -0,0 +1,36 @@
+#version 140
+
+// The Model View Projection matrix, where the view and projection are from the
+// point of view of the light casting the shadows
+uniform mat4 mvp;
+
+// The joint matrices for each joint
+//
+// https://github.com/KhronosGroup/glTF-Tutorials/blob/89bb8706ec3037a38e5ed1b77b5e6a4c3038db3d/gltfTutorial/gltfTutorial_020_Skins.md#the-joint-matrices
+uniform sampler2D joint_matrices;
+
+in vec3 position;
+
+// Indexes into joint_matrices
+in uvec4 joint_influences;
+in vec4 joint_weights;
+
+mat4 joint_matrix(uint i) {
+    return mat4(
+        texelFetch(joint_matrices, ivec2(i, 0), 0),
+        texelFetch(joint_matrices, ivec2(i, 1), 0),
+        texelFetch(joint_matrices, ivec2(i, 2), 0),
+        texelFetch(joint_matrices, ivec2(i, 3), 0)
+    );
+}
+
+void main() {
+    mat4 skin_mat =
+        joint_weights.x * joint_matrix(joint_influences.x) +
+        joint_weights.y * joint_matrix(joint_influences.y) +
+        joint_weights.z * joint_matrix(joint_influences.z) +
+        joint_weights.w * joint_matrix(joint_influences.w);
+
+    // Transforms the position to the clip space of the light
+    gl_Position = mvp * skin_mat * vec4(position, 1.0);
+}
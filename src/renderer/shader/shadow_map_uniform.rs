@@ -0,0 +1,51 @@
+use glium::uniforms::{
+    Uniforms,
+    UniformValue,
+    SamplerBehavior,
+    MinifySamplerFilter,
+    MagnifySamplerFilter,
+    SamplerWrapFunction,
+};
+
+use crate::renderer::ShadowMap;
+
+/// This struct must match the shadow map uniforms in the cel and ground shaders
+pub struct ShadowMapUniform<'a> {
+    shadow_map: Option<(UniformValue<'a>, UniformValue<'static>)>,
+}
+
+impl<'b> Uniforms for ShadowMapUniform<'b> {
+    fn visit_values<'a, F: FnMut(&str, UniformValue<'a>)>(&'a self, mut visit: F) {
+        let &Self {shadow_map} = self;
+        if let Some((shadow_map, light_space)) = shadow_map {
+            visit("use_shadow_map", UniformValue::Bool(true));
+            visit("shadow_map", shadow_map);
+            visit("light_space", light_space);
+        } else {
+            visit("use_shadow_map", UniformValue::Bool(false));
+        }
+    }
+}
+
+impl<'a> ShadowMapUniform<'a> {
+    pub fn new(shadow_map: Option<&'a ShadowMap>) -> Self {
+        let shadow_map = shadow_map.map(|shadow_map| {
+            let ShadowMap {depth_texture, light_space, light_index: _} = shadow_map;
+
+            // Interpolating depths doesn't make sense, so the nearest depth is always used
+            let behavior = SamplerBehavior {
+                minify_filter: MinifySamplerFilter::Nearest,
+                magnify_filter: MagnifySamplerFilter::Nearest,
+                wrap_function: (SamplerWrapFunction::Clamp, SamplerWrapFunction::Clamp, SamplerWrapFunction::Clamp),
+                ..Default::default()
+            };
+
+            (
+                UniformValue::DepthTexture2d(depth_texture, Some(behavior)),
+                UniformValue::Mat4(light_space.into_col_arrays()),
+            )
+        });
+
+        Self {shadow_map}
+    }
+}
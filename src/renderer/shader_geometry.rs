@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use glium::{
@@ -13,7 +14,7 @@ use glium::{
 use thiserror::Error;
 
 use crate::math::{Vec2, Vec3, Vec4, Mat4};
-use crate::scene::{Geometry, TexImage};
+use crate::scene::{Geometry, PrimitiveMode, TexImage};
 use crate::renderer::{Display, ShaderMaterial, JointMatrixTexture};
 
 #[derive(Debug, Error)]
@@ -24,9 +25,24 @@ pub enum ShaderGeometryError {
     TextureCreationError(#[from] TextureCreationError),
 }
 
-/// Geometry stored on the GPU
+/// Geometry stored on the GPU, positioned and posed for a single frame
+///
+/// Only the transforms change from frame to frame, so the buffers are shared between every frame
+/// that draws the same geometry. The buffers belong to the render context of a single thread, so
+/// they are never shared across threads.
 #[derive(Debug)]
 pub struct ShaderGeometry {
+    pub buffers: Rc<GeometryBuffers>,
+    pub joint_matrices: Arc<JointMatrixTexture>,
+    /// The world transform of this geometry
+    pub model_transform: Mat4,
+}
+
+/// The vertex data and material of some geometry stored on the GPU
+///
+/// This data never changes after it is uploaded.
+#[derive(Debug)]
+pub struct GeometryBuffers {
     pub indices: IndexBuffer<u32>,
     pub positions: VertexBuffer<Vec3>,
     pub normals: VertexBuffer<Vec3>,
@@ -34,19 +50,14 @@ pub struct ShaderGeometry {
     pub joint_influences: VertexBuffer<[u32; 4]>,
     pub joint_weights: VertexBuffer<Vec4>,
 
-    pub joint_matrices: Arc<JointMatrixTexture>,
     pub material: ShaderMaterial,
-    /// The world transform of this geometry
-    pub model_transform: Mat4,
 }
 
-impl ShaderGeometry {
+impl GeometryBuffers {
     /// Uploads the given geometry to the GPU
     pub fn new(
         display: &Display,
         geo: &Geometry,
-        joint_matrices: &Arc<JointMatrixTexture>,
-        model_transform: Mat4,
         image_lookup: impl FnMut(&TexImage) -> Result<Arc<Texture2d>, TextureCreationError>,
     ) -> Result<Self, ShaderGeometryError> {
         const POSITION_ATTR_TYPE: AttributeType = AttributeType::F32F32F32;
@@ -76,7 +87,7 @@ impl ShaderGeometry {
             (Cow::Borrowed("joint_weights"), 0, JOINT_WEIGHTS_ATTR_TYPE, false),
         ]);
 
-        let Geometry {name: _, indices, positions, normals, tex_coords, joint_influences, joint_weights, material} = geo;
+        let Geometry {name: _, mode, indices, positions, normals, tex_coords, joint_influences, joint_weights, material} = geo;
 
         let tex_coords = match tex_coords {
             Some(tex_coords) => Cow::Borrowed(tex_coords),
@@ -112,11 +123,17 @@ impl ShaderGeometry {
 
         let material = ShaderMaterial::new(material, image_lookup)?;
 
+        let primitive_type = match mode {
+            PrimitiveMode::Triangles => PrimitiveType::TrianglesList,
+            PrimitiveMode::Lines => PrimitiveType::LinesList,
+            PrimitiveMode::Points => PrimitiveType::Points,
+        };
+
         // NOTE: By using `immutable`, we are guranteeing that the data in these buffers will
         //   *never* change.
         // See: https://docs.rs/glium/0.26.0/glium/buffer/enum.BufferMode.html
         Ok(Self {
-            indices: IndexBuffer::immutable(display, PrimitiveType::TrianglesList, indices)?,
+            indices: IndexBuffer::immutable(display, primitive_type, indices)?,
 
             // These calls to new_raw are safe assuming that the specified attribute types
             // correspond to the types of the items stored in data passed to `new_raw`. This should
@@ -137,9 +154,7 @@ impl ShaderGeometry {
             joint_weights: unsafe { VertexBuffer::new_raw(display, joint_weights.as_ref(),
                 joint_weights_bindings, JOINT_WEIGHTS_ATTR_TYPE.get_size_bytes())? },
 
-            joint_matrices: joint_matrices.clone(),
             material,
-            model_transform,
         })
     }
 }
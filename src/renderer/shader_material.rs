@@ -6,27 +6,55 @@ use glium::{
     uniforms::{SamplerWrapFunction, MinifySamplerFilter, MagnifySamplerFilter},
 };
 
-use crate::math::Rgba;
+use crate::math::{Rgb, Rgba};
 use crate::scene::{Material, Texture, TexImage};
 
 /// A material that can be used on the GPU
 #[derive(Debug)]
 pub struct ShaderMaterial {
+    /// The name of the material, used to look up the highlights configured for it
+    pub name: Option<String>,
     pub diffuse_color: Rgba,
     pub texture: Option<ShaderTexture>,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emissive_color: Rgb,
+    pub emissive_texture: Option<ShaderTexture>,
+    pub unlit: bool,
 }
 
 impl ShaderMaterial {
     pub fn new(
         material: &Material,
-        image_lookup: impl FnMut(&TexImage) -> Result<Arc<Texture2d>, TextureCreationError>,
+        mut image_lookup: impl FnMut(&TexImage) -> Result<Arc<Texture2d>, TextureCreationError>,
     ) -> Result<Self, TextureCreationError> {
-        let &Material {diffuse_color, ref texture} = material;
+        let &Material {
+            ref name,
+            diffuse_color,
+            ref texture,
+            metallic,
+            roughness,
+            emissive_color,
+            ref emissive_texture,
+            unlit,
+        } = material;
         let texture = texture.as_ref()
-            .map(|tex| ShaderTexture::new(tex, image_lookup))
+            .map(|tex| ShaderTexture::new(tex, &mut image_lookup))
+            .transpose()?;
+        let emissive_texture = emissive_texture.as_ref()
+            .map(|tex| ShaderTexture::new(tex, &mut image_lookup))
             .transpose()?;
 
-        Ok(Self {diffuse_color, texture})
+        Ok(Self {
+            name: name.clone(),
+            diffuse_color,
+            texture,
+            metallic,
+            roughness,
+            emissive_color,
+            emissive_texture,
+            unlit,
+        })
     }
 }
 
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use glium::{
+    Surface,
+    framebuffer::SimpleFrameBuffer,
+    texture::{DepthTexture2d, DepthFormat, MipmapsOption},
+};
+use thiserror::Error;
+
+use crate::math::{Mat4, Vec3, FrustumPlanes, Decompose, Transforms};
+use crate::scene::LightType;
+
+use super::{Display, Shaders, ShaderGeometry, GeometryBuffers, Light, Shadows};
+use super::shader::shadow::ShadowUniforms;
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub enum ShadowMapError {
+    TextureCreationError(#[from] glium::texture::TextureCreationError),
+    FrameBufferValidationError(#[from] glium::framebuffer::ValidationError),
+    DrawError(#[from] glium::DrawError),
+}
+
+/// The depth of the scene as seen from the light that casts shadows
+#[derive(Debug)]
+pub struct ShadowMap {
+    /// The distance to the closest surface for each texel, as seen from the light
+    pub depth_texture: DepthTexture2d,
+    /// Transforms world coordinates into the clip space of the light
+    pub light_space: Mat4,
+    /// The index of the light that casts shadows
+    pub light_index: usize,
+}
+
+impl ShadowMap {
+    /// Renders a shadow map for the first directional or spot light, or returns None if none of
+    /// the lights can cast shadows
+    pub(in super) fn render(
+        display: &Display,
+        shaders: &Shaders,
+        geometry: &[ShaderGeometry],
+        lights: &[Arc<Light>],
+        shadows: &Shadows,
+    ) -> Result<Option<Self>, ShadowMapError> {
+        let &Shadows {extent, map_size, ..} = shadows;
+
+        let found = lights.iter().enumerate()
+            .find_map(|(i, light)| light_space(light, extent).map(|light_space| (i, light_space)));
+        let (light_index, light_space) = match found {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+
+        let depth_texture = DepthTexture2d::empty_with_format(display, DepthFormat::F32,
+            MipmapsOption::NoMipmap, map_size.get(), map_size.get())?;
+
+        {
+            let mut target = SimpleFrameBuffer::depth_only(display, &depth_texture)?;
+            target.clear_depth(1.0);
+
+            let params = glium::DrawParameters {
+                depth: glium::Depth {
+                    test: glium::draw_parameters::DepthTest::IfLess,
+                    write: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            for geo in geometry {
+                let ShaderGeometry {buffers, joint_matrices, model_transform} = geo;
+                let GeometryBuffers {indices, positions, joint_influences, joint_weights, ..} = &**buffers;
+
+                // Lines and points are too thin to cast a meaningful shadow
+                if indices.get_primitives_type() != glium::index::PrimitiveType::TrianglesList {
+                    continue;
+                }
+
+                let shadow_uniforms = super::shader::shadow::Shadow::from(ShadowUniforms {
+                    mvp: light_space * *model_transform,
+                    joint_matrices,
+                });
+
+                target.draw((positions, joint_influences, joint_weights), indices,
+                    &shaders.shadow, &shadow_uniforms, &params)?;
+            }
+        }
+
+        Ok(Some(Self {depth_texture, light_space, light_index}))
+    }
+}
+
+/// Returns the matrix that transforms world coordinates into the clip space of the given light,
+/// or None if the light cannot cast shadows
+///
+/// The extent is the size of the region around the origin that should be able to have shadows.
+fn light_space(light: &Light, extent: f32) -> Option<Mat4> {
+    let Light {data, world_transform} = light;
+    let Transforms {scale: _, rotation, translation: position} = world_transform.decompose();
+    let direction = rotation * Vec3 {x: 0.0, y: 0.0, z: -1.0};
+
+    // The up vector must not be parallel to the direction of the light
+    let up = if direction.y.abs() > 0.99 { Vec3::unit_z() } else { Vec3::up() };
+
+    use LightType::*;
+    match &**data {
+        // Point lights shine in every direction, so they would need a cube map
+        Point {..} => None,
+
+        Directional {..} => {
+            // Directional lights have no position, so the light is placed far enough away from
+            // the origin that the entire region is in front of it
+            let eye = -direction * extent;
+            let view = Mat4::look_at_rh(eye, Vec3::zero(), up);
+            let projection = Mat4::orthographic_rh_no(FrustumPlanes {
+                left: -extent/2.0,
+                right: extent/2.0,
+                bottom: -extent/2.0,
+                top: extent/2.0,
+                near: 0.0,
+                far: 2.0*extent,
+            });
+
+            Some(projection * view)
+        },
+
+        Spot {range, outer_cone_angle, ..} => {
+            let view = Mat4::look_at_rh(position, position + direction, up);
+            // The field of view must be less than 180 degrees for the projection to be valid
+            let fov = (2.0 * outer_cone_angle.get_radians()).min(170.0f32.to_radians()).max(0.01);
+            let projection = Mat4::perspective_rh_no(fov, 1.0, 0.05, range.unwrap_or(100.0));
+
+            Some(projection * view)
+        },
+    }
+}
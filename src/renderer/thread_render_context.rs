@@ -32,9 +32,11 @@ use std::num::NonZeroU32;
 
 use glium::{
     Program,
+    Surface,
+    BlitTarget,
     framebuffer::SimpleFrameBuffer,
+    uniforms::MagnifySamplerFilter,
     texture::{
-        RawImage2d,
         Texture2d,
         UncompressedFloatFormat,
         MipmapsOption,
@@ -47,19 +49,28 @@ use glium::glutin::{
     dpi::PhysicalSize,
     event_loop::EventLoop,
 };
-use image::{RgbaImage, imageops::flip_vertical_in_place};
 use thiserror::Error;
 
+use crate::math::Rgb;
+
 use crate::query3d::{QueryBackend, QueryError};
 
 use super::{
     Renderer,
     RenderedImage,
+    PendingImage,
     Size,
     FileQuery,
+    GeometrySource,
+    ShaderGeometry,
     Camera,
-    layout::{LayoutNode, LayoutError},
-    imageops::{scale_to_fit, copy},
+    Lighting,
+    LightTexture,
+    most_influential_lights,
+    ShadowMap,
+    ShadowMapError,
+    Shadows,
+    layout::{LayoutNode, LayoutOffset, LayoutError},
 };
 
 #[derive(Debug, Error)]
@@ -83,7 +94,9 @@ pub enum BeginRenderError {
 pub enum DrawLayoutError {
     BeginRenderError(#[from] BeginRenderError),
     DrawError(#[from] glium::DrawError),
-    ReadError(#[from] glium::ReadError),
+    ReadError(#[from] glium::buffer::ReadError),
+    ShadowMapError(#[from] ShadowMapError),
+    TextureCreationError(#[from] glium::texture::TextureCreationError),
     QueryError(#[from] QueryError),
     LayoutError(#[from] LayoutError),
 }
@@ -93,6 +106,10 @@ pub(in super) struct Shaders {
     pub cel: Program,
     /// The outline shader used for drawing an outline around the sprites
     pub outline: Program,
+    /// The shader used for drawing the depth of the scene from the point of view of a light
+    pub shadow: Program,
+    /// The shader used for drawing a drop shadow on an invisible ground plane
+    pub ground: Program,
 }
 
 /// The data backing one of the Renderers
@@ -133,12 +150,46 @@ pub struct ThreadRenderContext {
     render_data: Vec<RenderData>,
 }
 
+/// Creates an event loop for the current thread
+///
+/// Where it is supported, this may be called from any thread so that each thread can have its own
+/// context. On other platforms (e.g. macOS), this must be called from the main thread.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+fn new_event_loop() -> EventLoop<()> {
+    use glium::glutin::platform::unix::EventLoopExtUnix;
+    EventLoop::new_any_thread()
+}
+
+#[cfg(windows)]
+fn new_event_loop() -> EventLoop<()> {
+    use glium::glutin::platform::windows::EventLoopExtWindows;
+    EventLoop::new_any_thread()
+}
+
+#[cfg(not(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+)))]
+fn new_event_loop() -> EventLoop<()> {
+    EventLoop::new()
+}
+
 impl ThreadRenderContext {
     /// Creates a new thread renderer.
     ///
     /// No other OpenGL context should be made current on this thread while this value exists.
     pub fn new() -> Result<Self, ContextCreationError> {
-        let event_loop = EventLoop::new();
+        let event_loop = new_event_loop();
 
         let display = Self::build_display(
             ContextBuilder::new().with_depth_buffer(24),
@@ -161,12 +212,28 @@ impl ThreadRenderContext {
             None,
         )?;
 
+        let shadow_shader = Program::from_source(
+            &display,
+            include_str!("shader/shadow.vs"),
+            include_str!("shader/shadow.fs"),
+            None,
+        )?;
+
+        let ground_shader = Program::from_source(
+            &display,
+            include_str!("shader/ground.vs"),
+            include_str!("shader/ground.fs"),
+            None,
+        )?;
+
         Ok(Self {
             _event_loop: event_loop,
             display,
             shaders: Shaders {
                 cel: cel_shader,
                 outline: outline_shader,
+                shadow: shadow_shader,
+                ground: ground_shader,
             },
             render_data: Vec::new(),
         })
@@ -228,49 +295,83 @@ impl ThreadRenderContext {
         }))
     }
 
-    /// Returns the image that was rendered
-    pub fn finish_render(&mut self, render_id: RenderId) -> Result<RgbaImage, glium::ReadError> {
+    /// Starts reading back the image that was rendered
+    pub fn finish_render(&mut self, render_id: RenderId) -> PendingImage {
         let RenderId(id) = render_id;
         let data = self.render_data.remove(id);
-        // This stops and performs the read synchronously. For max parallelism, we'd probably want
-        // read_to_pixel_buffer().
-        let image: RawImage2d<u8> = data.color_texture.read();
-        let mut image = RgbaImage::from_raw(image.width, image.height, image.data.into_owned())
-            .expect("bug: image data buffer did not match expected size for width and height");
-        flip_vertical_in_place(&mut image);
-
-        Ok(image)
+
+        self.read(&data.color_texture)
     }
 
-    /// Scales the given image up, with no anti-aliasing or other interpolation of any kind.
-    pub fn scale(&mut self, image: &RgbaImage, scale: NonZeroU32) -> Result<RgbaImage, DrawLayoutError> {
-        //TODO: Do this scaling using the GPU. Should the error type still be DrawLayoutError?
+    /// Starts reading the given texture back from the GPU
+    pub fn read(&self, texture: &Texture2d) -> PendingImage {
+        PendingImage::new(texture.read_to_pixel_buffer())
+    }
 
-        //TODO: Could optimize the case of scale == 1
+    /// Scales the given image up, with no anti-aliasing or other interpolation of any kind.
+    pub fn scale(&mut self, image: Texture2d, scale: NonZeroU32) -> Result<Texture2d, DrawLayoutError> {
         let scale = scale.get();
+        if scale == 1 {
+            return Ok(image);
+        }
+
         let (width, height) = image.dimensions();
-        let mut scaled_image = RgbaImage::new(width * scale, height * scale);
-        scale_to_fit(&image, &mut scaled_image);
+        let scaled_image = self.empty_canvas(width * scale, height * scale)?;
+        image.as_surface().blit_whole_color_to(
+            &scaled_image.as_surface(),
+            &BlitTarget {
+                left: 0,
+                bottom: 0,
+                width: (width * scale) as i32,
+                height: (height * scale) as i32,
+            },
+            MagnifySamplerFilter::Nearest,
+        );
 
         Ok(scaled_image)
     }
 
-    /// Draws the given layout, returning the image that was rendered
-    pub fn draw(&mut self, layout: LayoutNode) -> Result<RgbaImage, DrawLayoutError> {
+    /// Draws the given layout, returning a texture containing the image that was rendered
+    pub fn draw(&mut self, layout: LayoutNode) -> Result<Texture2d, DrawLayoutError> {
         let Size {width, height} = layout.size();
 
-        let mut final_image = RgbaImage::new(width.get(), height.get());
-        for (offset, node) in layout.iter_targets() {
+        let canvas = self.empty_canvas(width.get(), height.get())?;
+        self.draw_into(layout, &canvas, LayoutOffset::default())?;
+
+        Ok(canvas)
+    }
+
+    /// Creates a texture that is entirely transparent
+    fn empty_canvas(&self, width: u32, height: u32) -> Result<Texture2d, BeginRenderError> {
+        let canvas = Texture2d::empty_with_format(&self.display,
+            UncompressedFloatFormat::F32F32F32F32, MipmapsOption::NoMipmap, width, height)?;
+        canvas.as_surface().clear_color(0.0, 0.0, 0.0, 0.0);
+
+        Ok(canvas)
+    }
+
+    /// Draws the given layout into the canvas with its top left corner at the given offset
+    fn draw_into(
+        &mut self,
+        layout: LayoutNode,
+        canvas: &Texture2d,
+        offset: LayoutOffset,
+    ) -> Result<(), DrawLayoutError> {
+        for (node_offset, node) in layout.iter_targets() {
+            let offset = LayoutOffset {
+                x: offset.x + node_offset.x,
+                y: offset.y + node_offset.y,
+            };
+
             use LayoutNode::*;
             match node {
                 RenderedImage(image) => {
                     let image = self.draw_render(image)?;
-                    copy(&image, &mut final_image, (offset.x, offset.y));
+                    copy(&image, canvas, offset);
                 },
 
                 Grid(_) => {
-                    let image = self.draw(node)?;
-                    copy(&image, &mut final_image, (offset.x, offset.y));
+                    self.draw_into(node, canvas, offset)?;
                 },
 
                 Empty {..} => {
@@ -279,25 +380,119 @@ impl ThreadRenderContext {
             }
         }
 
-        Ok(final_image)
+        Ok(())
     }
 
-    fn draw_render(&mut self, image: RenderedImage) -> Result<RgbaImage, DrawLayoutError> {
-        let RenderedImage {size, background, camera, lights, ambient_light, geometry, outline} = image;
-        let FileQuery {query, file} = geometry;
+    fn draw_render(&mut self, image: RenderedImage) -> Result<Texture2d, DrawLayoutError> {
+        let RenderedImage {
+            size,
+            background,
+            camera,
+            lights,
+            max_lights,
+            ambient_light,
+            geometry,
+            outline,
+            shadows,
+            highlights,
+            color_transfer,
+        } = image;
         let Camera {view, projection} = *camera.fetch_camera()?;
         let lights = lights.fetch_lights()?;
 
+        // All of the geometry is needed up front so that it can cast shadows on itself
+        let mut shader_geometry = Vec::new();
+        for source in geometry {
+            // The attached node may be in the same file, so this must happen before that file is
+            // locked below
+            let transform = source.fetch_transform()?;
+
+            let GeometrySource {geometry: FileQuery {query, file}, ..} = source;
+            let mut file = file.lock().expect("bug: file lock was poisoned");
+            let geos = file.query_geometry(&query, &self.display)?;
+            // Only the transform changes, so the buffers on the GPU can still be shared
+            shader_geometry.extend(geos.iter().map(|geo| ShaderGeometry {
+                buffers: geo.buffers.clone(),
+                joint_matrices: geo.joint_matrices.clone(),
+                model_transform: transform * geo.model_transform,
+            }));
+        }
+
+        let shadow_map = match &shadows {
+            Some(shadows) => {
+                ShadowMap::render(&self.display, &self.shaders, &shader_geometry, &lights, shadows)?
+            },
+            None => None,
+        };
+        let shadow_tint = shadows.as_ref().map(|shadows| shadows.tint).unwrap_or(Rgb::white() * 0.1);
+
+        // Each piece of geometry may be lit by a different set of lights, so each one is given the
+        // index of its light texture and the index of the shadow casting light in that texture
+        let mut light_textures = Vec::new();
+        let mut geometry_lights = Vec::new();
+        match max_lights {
+            Some(max_lights) => for geo in &shader_geometry {
+                // The origin of the geometry is only a rough estimate of where a skinned mesh is,
+                // since its joints can move its vertices far away from that point
+                let center = geo.model_transform.cols.w.xyz();
+                let selected = most_influential_lights(&lights, max_lights.get() as usize, center);
+
+                let shadow_light = shadow_map.as_ref()
+                    .and_then(|shadow_map| selected.iter().position(|&i| i == shadow_map.light_index));
+                light_textures.push(LightTexture::new(&self.display, selected.iter().map(|&i| &lights[i]))?);
+                geometry_lights.push((light_textures.len() - 1, shadow_light));
+            },
+
+            // Without a limit, every piece of geometry is lit by every light
+            None => {
+                let shadow_light = shadow_map.as_ref().map(|shadow_map| shadow_map.light_index);
+                light_textures.push(LightTexture::new(&self.display, lights.iter())?);
+                geometry_lights.resize(shader_geometry.len(), (0, shadow_light));
+            },
+        }
+
         let (render_id, mut renderer) = self.begin_render(size)?;
         renderer.clear(background);
 
-        let mut file = file.lock().expect("bug: file lock was poisoned");
-        let geos = file.query_geometry(&query, renderer.display())?;
-        for geo in &*geos {
-            renderer.render(&*geo, &lights, ambient_light, view, projection, &outline)?;
+        for (geo, &(texture_index, shadow_light)) in shader_geometry.iter().zip(&geometry_lights) {
+            let lighting = Lighting {
+                lights: &light_textures[texture_index],
+                shadow_light,
+                ambient_light,
+                shadow_tint,
+                shadow_map: shadow_map.as_ref(),
+                highlights: &highlights,
+                color_transfer,
+            };
+            renderer.render(geo, &lighting, view, projection, &outline)?;
         }
 
-        let image = self.finish_render(render_id)?;
-        Ok(image)
+        if let Some(Shadows {extent, drop_shadow: Some(drop_shadow), ..}) = &shadows {
+            renderer.render_drop_shadow(drop_shadow, *extent, view, projection, shadow_map.as_ref())?;
+        }
+
+        let RenderId(id) = render_id;
+        let RenderData {color_texture, depth_texture: _} = self.render_data.remove(id);
+        Ok(color_texture)
     }
 }
+
+/// Copies the entire source texture into the target texture with the top left corner of the
+/// source at the given offset
+fn copy(source: &Texture2d, target: &Texture2d, offset: LayoutOffset) {
+    let (width, height) = source.dimensions();
+    let target_height = target.height();
+
+    // OpenGL measures from the bottom left corner, but offsets are from the top left corner
+    let bottom = target_height - offset.y - height;
+    source.as_surface().blit_whole_color_to(
+        &target.as_surface(),
+        &BlitTarget {
+            left: offset.x,
+            bottom,
+            width: width as i32,
+            height: height as i32,
+        },
+        MagnifySamplerFilter::Nearest,
+    );
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::math::{Vec2, Vec3, Vec4};
+use crate::query3d::{FileError, ItemRef};
 
 use super::Material;
 
@@ -9,7 +10,9 @@ pub struct Geometry {
     /// The name of the primitive (possibly empty), or None if the 3D file this was loaded from does
     /// not support mesh names
     pub name: Option<String>,
-    /// The indexes that represent the triangles of the geometry
+    /// The kind of primitive drawn using the indexes
+    pub mode: PrimitiveMode,
+    /// The indexes that represent the triangles, lines or points of the geometry
     pub indices: Vec<u32>,
     /// The position of each vertex of the geometry
     pub positions: Vec<Vec3>,
@@ -39,12 +42,26 @@ pub struct Geometry {
     pub material: Arc<Material>,
 }
 
+/// The kind of primitive that the indexes of some geometry represent
+///
+/// Strips, fans and loops are converted to one of these when the geometry is loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveMode {
+    /// Every 3 indexes form a triangle
+    Triangles,
+    /// Every 2 indexes form a line, drawn one pixel wide
+    Lines,
+    /// Every index is a point, drawn as a single pixel
+    Points,
+}
+
 impl Geometry {
     pub fn from_obj(model: tobj::Model, materials: &[Arc<Material>]) -> Self {
         let tobj::Model {name, mesh} = model;
 
         Self {
             name: Some(name),
+            mode: PrimitiveMode::Triangles,
             indices: mesh.indices,
             positions: mesh.positions.chunks(3).map(|sl| Vec3::from_slice(sl)).collect(),
             normals: mesh.normals.chunks(3).map(|sl| Vec3::from_slice(sl)).collect(),
@@ -57,27 +74,50 @@ impl Geometry {
 
     pub fn from_gltf(
         prim: gltf::Primitive,
+        mesh: &ItemRef,
         materials: &[Arc<Material>],
         buffers: &[gltf::buffer::Data],
-    ) -> Self {
-        assert_eq!(prim.mode(), gltf::mesh::Mode::Triangles, "Non-triangle meshes are not supported");
+    ) -> Result<Self, FileError> {
+        let primitive = prim.index();
+        let missing = |attribute| FileError::MissingPrimitiveAttribute {
+            mesh: mesh.clone(),
+            primitive,
+            attribute,
+        };
 
         // glTF primitives do not have names
         let name = None;
 
         let reader = prim.reader(|buffer| Some(&buffers[buffer.index()]));
-        let indices = reader.read_indices()
-            .expect("Unable to read index buffer from glTF geometry")
-            .into_u32()
-            .collect();
         let positions: Vec<_> = reader.read_positions()
-            .expect("Unable to read vertex positions from glTF geometry")
-            .map(Vec3::from)
-            .collect();
-        let normals: Vec<_> = reader.read_normals()
-            .expect("Unable to read vertex normals from glTF geometry")
+            .ok_or_else(|| missing("vertex positions"))?
             .map(Vec3::from)
             .collect();
+        // Primitives without indices use each vertex once, in order
+        let indices: Vec<_> = match reader.read_indices() {
+            Some(indices) => indices.into_u32().collect(),
+            None => (0..positions.len() as u32).collect(),
+        };
+
+        use gltf::mesh::Mode;
+        let (mode, indices) = match prim.mode() {
+            Mode::Triangles => (PrimitiveMode::Triangles, indices),
+            Mode::TriangleStrip => (PrimitiveMode::Triangles, triangulate_strip(&indices)),
+            Mode::TriangleFan => (PrimitiveMode::Triangles, triangulate_fan(&indices)),
+            Mode::Lines => (PrimitiveMode::Lines, indices),
+            Mode::LineStrip => (PrimitiveMode::Lines, line_strip_to_lines(&indices, false)),
+            Mode::LineLoop => (PrimitiveMode::Lines, line_strip_to_lines(&indices, true)),
+            Mode::Points => (PrimitiveMode::Points, indices),
+        };
+
+        // Not handling optional normals for triangles yet. Lines and points often do not have
+        // normals, so they get zero normals instead (which leaves them in their darkest shade).
+        let normals: Vec<_> = match (reader.read_normals(), mode) {
+            (Some(normals), _) => normals.map(Vec3::from).collect(),
+            (None, PrimitiveMode::Triangles) => return Err(missing("vertex normals")),
+            (None, PrimitiveMode::Lines) |
+            (None, PrimitiveMode::Points) => vec![Vec3::zero(); positions.len()],
+        };
         // We only support TEXCOORD_0
         let tex_coords: Option<Vec<_>> = reader.read_tex_coords(0)
             .map(|tex_coords| tex_coords.into_f32().map(Vec2::from).collect());
@@ -91,29 +131,97 @@ impl Geometry {
 
         // index() returns None if the material is the glTF default material
         // See: https://github.com/KhronosGroup/glTF/tree/92f59a0dbefe2d54cff38dba103cd70462cc778b/specification/2.0#default-material
-        let material = prim.material().index()
+        let material: Arc<Material> = prim.material().index()
             .map(|id| materials[id].clone())
             .unwrap_or_default();
 
-        // Not handling optional normals yet
-        assert_eq!(positions.len(), normals.len(),
-            "glTF geometry must have exactly as many normals as vertices");
+        let check_count = |attribute, count| {
+            if count == positions.len() {
+                Ok(())
+            } else {
+                Err(FileError::PrimitiveAttributeCount {
+                    mesh: mesh.clone(),
+                    primitive,
+                    attribute,
+                    count,
+                    vertices: positions.len(),
+                })
+            }
+        };
+
+        check_count("vertex normals", normals.len())?;
+
+        match (&tex_coords, &material.texture) {
+            (Some(tex_coords), _) => check_count("texture coordinates", tex_coords.len())?,
+            (None, Some(_)) => return Err(missing("texture coordinates")),
+            (None, None) => {},
+        }
 
         match (&joint_influences, &joint_weights) {
             (Some(joint_influences), Some(joint_weights)) => {
-                assert_eq!(positions.len(), joint_influences.len(),
-                    "glTF geometry must have exactly as many vertex joint influences as vertices");
-                assert_eq!(positions.len(), joint_weights.len(),
-                    "glTF geometry must have exactly as many vertex weights as vertices");
+                check_count("vertex joint influences", joint_influences.len())?;
+                check_count("vertex weights", joint_weights.len())?;
             },
 
-            (Some(_), None) | (None, Some(_)) => {
-                unreachable!("glTF geometry must always either have both vertex joint influences and vertex weights, or neither");
-            },
+            (Some(_), None) => return Err(missing("vertex weights")),
+            (None, Some(_)) => return Err(missing("vertex joint influences")),
 
             (None, None) => {},
         }
 
-        Self {name, indices, positions, normals, tex_coords, joint_influences, joint_weights, material}
+        Ok(Self {name, mode, indices, positions, normals, tex_coords, joint_influences, joint_weights, material})
+    }
+}
+
+/// Converts the indexes of a triangle strip into a list of triangles
+///
+/// Every other triangle is flipped so that all of the triangles have the same winding order.
+fn triangulate_strip(indices: &[u32]) -> Vec<u32> {
+    indices.windows(3).enumerate().flat_map(|(i, tri)| {
+        if i % 2 == 0 {
+            vec![tri[0], tri[1], tri[2]]
+        } else {
+            vec![tri[1], tri[0], tri[2]]
+        }
+    }).collect()
+}
+
+/// Converts the indexes of a triangle fan into a list of triangles
+fn triangulate_fan(indices: &[u32]) -> Vec<u32> {
+    match indices.split_first() {
+        Some((&center, rest)) => rest.windows(2).flat_map(|edge| vec![center, edge[0], edge[1]]).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Converts the indexes of a line strip into a list of lines, connecting the last index back to
+/// the first if `closed` is true
+fn line_strip_to_lines(indices: &[u32], closed: bool) -> Vec<u32> {
+    let mut lines: Vec<_> = indices.windows(2).flat_map(|line| vec![line[0], line[1]]).collect();
+    if let (true, Some(&first), Some(&last)) = (closed, indices.first(), indices.last()) {
+        if indices.len() > 2 {
+            lines.extend_from_slice(&[last, first]);
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_and_fans() {
+        assert_eq!(triangulate_strip(&[0, 1, 2, 3, 4]), vec![0, 1, 2, 2, 1, 3, 2, 3, 4]);
+        assert_eq!(triangulate_strip(&[0, 1]), Vec::<u32>::new());
+
+        assert_eq!(triangulate_fan(&[0, 1, 2, 3]), vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(triangulate_fan(&[]), Vec::<u32>::new());
+
+        assert_eq!(line_strip_to_lines(&[0, 1, 2], false), vec![0, 1, 1, 2]);
+        assert_eq!(line_strip_to_lines(&[0, 1, 2], true), vec![0, 1, 1, 2, 2, 0]);
+        // A loop of two points is just a single line
+        assert_eq!(line_strip_to_lines(&[0, 1], true), vec![0, 1]);
     }
 }
@@ -1,13 +1,26 @@
 use std::sync::Arc;
 
-use crate::math::Rgba;
+use crate::math::{Rgb, Rgba};
+use crate::query3d::{FileError, ItemRef};
 
 use super::Texture;
 
 #[derive(Debug)]
 pub struct Material {
+    /// The name of the material, if any
+    pub name: Option<String>,
     pub diffuse_color: Rgba,
     pub texture: Option<Arc<Texture>>,
+    /// How metallic the material is, between 0.0 (not metallic) and 1.0 (metal)
+    pub metallic: f32,
+    /// How rough the surface of the material is, between 0.0 (smooth) and 1.0 (rough)
+    pub roughness: f32,
+    /// The color of the light emitted by the material, added on top of any lighting
+    pub emissive_color: Rgb,
+    /// A texture multiplied by the emissive color, if any
+    pub emissive_texture: Option<Arc<Texture>>,
+    /// If true, the material ignores all lighting and is drawn using its diffuse color as is
+    pub unlit: bool,
 }
 
 impl Default for Material {
@@ -15,8 +28,14 @@ impl Default for Material {
         // Based on the default material in glTF
         // See: https://github.com/KhronosGroup/glTF/tree/92f59a0dbefe2d54cff38dba103cd70462cc778b/specification/2.0#reference-pbrmetallicroughness
         Self {
+            name: None,
             diffuse_color: Rgba::white(),
             texture: None,
+            metallic: 1.0,
+            roughness: 1.0,
+            emissive_color: Rgb::black(),
+            emissive_texture: None,
+            unlit: false,
         }
     }
 }
@@ -24,22 +43,57 @@ impl Default for Material {
 impl From<tobj::Material> for Material {
     fn from(mat: tobj::Material) -> Self {
         Self {
+            name: Some(mat.name),
             diffuse_color: Rgba::from_opaque(mat.diffuse),
             texture: None,
+            metallic: 0.0,
+            roughness: shininess_to_roughness(mat.shininess),
+            emissive_color: Rgb::black(),
+            emissive_texture: None,
+            unlit: false,
         }
     }
 }
 
 impl Material {
-    pub fn from_gltf(mat: gltf::Material, textures: &[Arc<Texture>]) -> Self {
+    pub fn from_gltf(mat: gltf::Material, textures: &[Arc<Texture>]) -> Result<Self, FileError> {
         let pbr = mat.pbr_metallic_roughness();
         let [r, g, b, a] = pbr.base_color_factor();
-        Self {
-            diffuse_color: Rgba {r, g, b, a},
-            texture: pbr.base_color_texture().map(|info| {
-                assert_eq!(info.tex_coord(), 0, "Only TEXCOORD_0 is supported in glTF files");
-                textures[info.texture().index()].clone()
+        let texture = |info: gltf::texture::Info| match info.tex_coord() {
+            0 => Ok(textures[info.texture().index()].clone()),
+            // This unwrap is safe because the default material is never loaded this way
+            tex_coord => Err(FileError::UnsupportedTexCoord {
+                material: ItemRef::new(mat.index().unwrap(), mat.name()),
+                tex_coord,
             }),
-        }
+        };
+
+        Ok(Self {
+            name: mat.name().map(String::from),
+            diffuse_color: Rgba {r, g, b, a},
+            texture: pbr.base_color_texture().map(texture).transpose()?,
+            metallic: pbr.metallic_factor(),
+            roughness: pbr.roughness_factor(),
+            emissive_color: Rgb::from(mat.emissive_factor()),
+            emissive_texture: mat.emissive_texture().map(texture).transpose()?,
+            unlit: mat.unlit(),
+        })
     }
 }
+
+/// Converts a Blinn-Phong specular exponent (e.g. the `Ns` value in an MTL file) to a roughness
+///
+/// This is the inverse of the conversion in `roughness_to_shininess`.
+fn shininess_to_roughness(shininess: f32) -> f32 {
+    (2.0 / (shininess.max(0.0) + 2.0)).powf(0.25)
+}
+
+/// Converts a roughness to the equivalent Blinn-Phong specular exponent
+///
+/// Source: http://simonstechblog.blogspot.com/2011/12/microfacet-brdf.html
+pub fn roughness_to_shininess(roughness: f32) -> f32 {
+    // Perfectly smooth materials would have an infinite exponent and perfectly rough materials
+    // would have no highlight at all
+    let roughness = roughness.max(0.01);
+    (2.0 / roughness.powi(4) - 2.0).clamp(1.0, 1024.0)
+}
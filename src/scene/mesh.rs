@@ -2,10 +2,19 @@ use std::sync::Arc;
 
 use rayon::prelude::*;
 
+use crate::query3d::{FileError, ItemRef};
+
 use super::{Material, Geometry};
 
+/// Identifies a mesh within the file it was loaded from
+///
+/// Like `ImageId`, this is only valid for the file that it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MeshId(pub usize);
+
 #[derive(Debug, Clone)]
 pub struct Mesh {
+    pub id: MeshId,
     /// The name of the mesh (possibly empty), or None if the 3D file this was loaded from does
     /// not support mesh names
     pub name: Option<String>,
@@ -16,6 +25,8 @@ pub struct Mesh {
 impl Mesh {
     pub fn from_obj(models: Vec<tobj::Model>, materials: &[Arc<Material>]) -> Self {
         Self {
+            // OBJ files are loaded as a single mesh
+            id: MeshId(0),
             // Currently, tobj doesn't fully model the hierarchy of objects and groups in OBJ files.
             // That means that the object name isn't actually accessible always. Even when it is,
             // it isn't possible to distinguish between objects and their groups.
@@ -31,12 +42,15 @@ impl Mesh {
         mesh: gltf::Mesh,
         materials: &[Arc<Material>],
         buffers: &[gltf::buffer::Data],
-    ) -> Self {
-        Self {
+    ) -> Result<Self, FileError> {
+        let mesh_ref = ItemRef::new(mesh.index(), mesh.name());
+
+        Ok(Self {
+            id: MeshId(mesh.index()),
             name: Some(mesh.name().unwrap_or("").to_string()),
             geometry: mesh.primitives()
-                .map(|prim| Geometry::from_gltf(prim, materials, buffers))
-                .collect(),
-        }
+                .map(|prim| Geometry::from_gltf(prim, &mesh_ref, materials, buffers))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
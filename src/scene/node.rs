@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::math::{Mat4, Quaternion};
+use crate::query3d::{FileError, ItemRef};
 
 use super::{NodeId, Mesh, Skin, CameraType, LightType};
 
@@ -31,7 +32,7 @@ impl Node {
         skins: &[Arc<Skin>],
         cameras: &[Arc<CameraType>],
         lights: &[Arc<LightType>],
-    ) -> Self {
+    ) -> Result<Self, FileError> {
         let id = NodeId::from_gltf(&node);
         let name = Some(node.name().unwrap_or("").to_string());
 
@@ -53,8 +54,12 @@ impl Node {
                 Some(NodeData::Light(lights[light.index()].clone()))
             },
 
-            (_, Some(_), _, _) => unreachable!("Did not expect a node that had a skin but no mesh"),
-            _ => unreachable!("Did not expect a node that had more than one of a mesh, camera, or light"),
+            (None, Some(_), _, _) => return Err(FileError::SkinWithoutMesh {
+                node: ItemRef::new(node.index(), node.name()),
+            }),
+            _ => return Err(FileError::MultipleNodeData {
+                node: ItemRef::new(node.index(), node.name()),
+            }),
         };
 
         use gltf::scene::Transform::*;
@@ -72,7 +77,7 @@ impl Node {
             },
         };
 
-        Self {id, name, data, transform}
+        Ok(Self {id, name, data, transform})
     }
 
     pub fn mesh(&self) -> Option<(&Arc<Mesh>, Option<&Arc<Skin>>)> {
@@ -1,30 +1,49 @@
 mod file_cache;
+mod worker;
+mod sampling;
+mod pivot;
+mod metadata;
+mod gif_output;
 
 pub use file_cache::*;
+pub use worker::*;
+pub use sampling::*;
+pub use pivot::*;
+pub use metadata::*;
+pub use gif_output::*;
 
+use std::io;
 use std::sync::{Arc, Mutex};
 use std::path::{Path, PathBuf};
 use std::num::NonZeroU32;
 use std::cmp::max;
+use std::collections::HashSet;
 
-use interpolation::lerp;
+use serde::Serialize;
 use thiserror::Error;
 
-use crate::math::{Mat4, Vec3, Rgb, Milliseconds};
+use crate::math::{Mat4, Vec2, Vec3, Rgb, Milliseconds};
 use crate::config;
 use crate::scene::{CameraType, LightType};
 use crate::query3d::{
     File,
     FileError,
+    QueryError,
+    QueryBackend,
     CameraQuery,
+    LightQuery,
     GeometryQuery,
     GeometryFilter,
     AnimationQuery,
     AnimationPosition,
+    AnimationLayer,
+    RootMotionLock,
+    NodeQuery,
 };
 use crate::renderer::{
     ThreadRenderContext,
     DrawLayoutError,
+    PendingImage,
     RenderJob,
     RenderNode,
     RenderLayout,
@@ -33,18 +52,51 @@ use crate::renderer::{
     RenderedImage,
     Size,
     Outline,
+    Shadows,
+    DropShadow,
+    DropShadowStyle,
+    MaterialHighlights,
+    Highlights,
+    RimLight,
+    Specular,
+    ColorTransfer,
+    AlphaOutput,
+    AlphaMode,
     Light,
     RenderLights,
     Camera,
     RenderCamera,
+    FollowCamera,
     FileQuery,
+    GeometrySource,
 };
 
 #[derive(Debug, Error)]
-#[error(transparent)]
 pub enum TaskError {
+    #[error(transparent)]
+    FileError(#[from] FileError),
+    #[error(transparent)]
+    QueryError(#[from] QueryError),
+    #[error(transparent)]
     DrawLayoutError(#[from] DrawLayoutError),
+    #[error(transparent)]
     ImageError(#[from] image::ImageError),
+    #[error(transparent)]
+    IoError(#[from] io::Error),
+    #[error("Invalid animation frame sampling: {reason}")]
+    InvalidFrameSampling {reason: String},
+    #[error("`{option}` has no effect without a `metadata_path` since it is only written to the metadata file")]
+    MissingMetadataPath {option: &'static str},
+    #[error("Only one of `scene` or `all_scenes` may be provided")]
+    ConflictingSceneSelection,
+    #[error("Multiple animations are named `{name}`")]
+    DuplicateAnimationName {name: String},
+    #[error("Animation layer weight must be between 0.0 and 1.0, got {weight}")]
+    InvalidLayerWeight {weight: f32},
+    #[error("Spritesheet must have at least one animation")]
+    EmptySpritesheet,
+    #[error("Animation `{name}` must have at least one frame")]
+    EmptyAnimation {name: String},
 }
 
 #[derive(Debug)]
@@ -53,14 +105,87 @@ pub struct Task {
     pub output_path: PathBuf,
     /// The job to execute that generates the final image
     pub job: RenderJob,
+    /// Information about each frame drawn in the generated image
+    pub frames: Vec<FrameInfo>,
+    /// The absolute path to output the metadata file describing each frame, or None if no
+    /// metadata file should be generated
+    pub metadata_path: Option<PathBuf>,
+    /// The animated GIFs to output from the frames of the generated image
+    pub gifs: Vec<GifOutput>,
+    /// The pivots of the frames that are projected when the image is drawn
+    pub pivots: Vec<PendingPivot>,
+    /// The size of each cell of the generated image in pixels, after scaling
+    pub cell_size: Size,
+}
+
+/// Information about a single frame of a generated image
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FrameInfo {
+    /// The name of the animation that the frame is from
+    #[serde(skip)]
+    pub animation: String,
+    /// The row of the frame in the generated image (always 0 for poses)
+    pub row: u32,
+    /// The column of the frame in the generated image (always 0 for poses)
+    pub col: u32,
+    /// How long the frame should be shown for when the animation is played, or None if that is
+    /// not known (e.g. for frames that are separate models)
+    pub duration: Option<Milliseconds>,
+    /// The translation removed from the model by locking its root motion, or None if the offsets
+    /// were not requested to be exported
+    pub root_offset: Option<Vec3>,
+    /// The pixel position of the pivot in the generated image, measured from the top left corner
+    /// of the cell containing the frame, or None if no pivot was configured
+    pub pivot: Option<Vec2>,
+    /// The attachment points and hitboxes of the frame, measured from the top left corner of the
+    /// cell containing the frame
+    #[serde(flatten)]
+    pub markers: FrameMarkers,
+}
+
+/// A task whose image has been drawn and is being read back from the GPU
+pub struct SubmittedTask {
+    output_path: PathBuf,
+    image: PendingImage,
+    frames: Vec<FrameInfo>,
+    metadata_path: Option<PathBuf>,
+    gifs: Vec<GifOutput>,
+    cell_size: Size,
 }
 
 impl Task {
     pub fn execute(self, ctx: &mut ThreadRenderContext) -> Result<(), TaskError> {
-        let Self {output_path, job} = self;
+        self.submit(ctx)?.finish()
+    }
+
+    /// Draws the image of the task without waiting for it to be read back from the GPU
+    pub fn submit(self, ctx: &mut ThreadRenderContext) -> Result<SubmittedTask, TaskError> {
+        let Self {output_path, job, mut frames, metadata_path, gifs, pivots, cell_size} = self;
+
+        let image = job.submit(ctx)?;
+        // Queried after drawing so that this runs on the same thread as the rest of the task
+        for pending in pivots {
+            frames[pending.frame].pivot = Some(pending.fetch_position()?);
+        }
+
+        Ok(SubmittedTask {output_path, image, frames, metadata_path, gifs, cell_size})
+    }
+}
 
-        let image = job.execute(ctx)?;
+impl SubmittedTask {
+    /// Waits for the image to be read back and writes it (and its metadata and GIFs) to disk
+    pub fn finish(self) -> Result<(), TaskError> {
+        let Self {output_path, image, frames, metadata_path, gifs, cell_size} = self;
+
+        let image = image.wait().map_err(DrawLayoutError::from)?;
         image.save(&output_path)?;
+        if let Some(metadata_path) = metadata_path {
+            write_metadata(&metadata_path, cell_size, &frames)?;
+        }
+        for GifOutput {path, animation} in gifs {
+            let frames = frames.iter().filter(|frame| frame.animation == animation);
+            write_gif(&path, &image, cell_size, frames)?;
+        }
 
         Ok(())
     }
@@ -70,19 +195,47 @@ pub fn generate_pose_task(
     pose: config::Pose,
     base_dir: &Path,
     file_cache: &mut WeakFileCache,
-) -> Result<Task, FileError> {
-    let config::Pose {model, path, width, height, camera, scale, background, outline} = pose;
+) -> Result<Task, TaskError> {
+    let config::Pose {
+        model,
+        path,
+        metadata_path,
+        width,
+        height,
+        camera,
+        lights,
+        scale,
+        background,
+        color_transfer,
+        alpha,
+        outline,
+        extra_models,
+        shadows,
+        rim_light,
+        specular,
+        materials,
+        max_lights,
+        pivot,
+        attachment_points,
+        hitboxes,
+    } = pose;
+    let color_transfer = config_to_color_transfer(color_transfer);
+    let extra_models = open_extra_models(extra_models, base_dir, file_cache)?;
 
     let (file, geometry) = match model {
-        config::PoseModel::GltfFrame {gltf, animation, time} => {
+        config::PoseModel::GltfFrame {gltf, scene, animation, time, layers} => {
+            validate_layers(&layers)?;
             let file = file_cache.open_gltf(&gltf.resolve(base_dir))?;
 
+            let position = AnimationPosition::Time(time);
             let geometry = FileQuery {
                 query: GeometryQuery {
-                    models: GeometryFilter::all_in_default_scene(),
+                    models: GeometryFilter::Scene {name: scene},
                     animation: Some(AnimationQuery {
                         name: animation,
-                        position: AnimationPosition::Time(time),
+                        layers: config_to_layers(&layers, &position),
+                        position,
+                        root_motion: None,
                     }),
                 },
                 file: file.clone(),
@@ -106,30 +259,67 @@ pub fn generate_pose_task(
         },
     };
 
+    let GeometryFilter::Scene {name: scene} = &geometry.query.models;
+    let animation = geometry.query.animation.as_ref();
+    let size = Size {width, height};
+    let camera = preset_to_camera(&camera, &file, scene.as_ref(), animation);
+    let mut pivots = Vec::new();
+    let (camera, pivot) = match &pivot {
+        Some(pivot) => match frame_pivot(pivot, camera, &file, scene.as_ref(), animation, size) {
+            (camera, PivotPixel::Aligned(position)) => (camera, Some(frame_to_cell(position, size, size, scale))),
+            (camera, PivotPixel::Projected(point)) => {
+                if metadata_path.is_none() {
+                    return Err(TaskError::MissingMetadataPath {option: "pivot"});
+                }
+                let frame_size = size;
+                pivots.push(PendingPivot {frame: 0, camera: camera.clone(), point, frame_size, cell_size: size, scale});
+                (camera, None)
+            },
+        },
+        None => (camera, None),
+    };
+    let markers = frame_markers(&attachment_points, &hitboxes, &camera, &file, scene.as_ref(), animation, size)?
+        .map_positions(|position| frame_to_cell(position, size, size, scale));
+    let lights = config_to_lights(lights.as_ref(), &file, scene.as_ref(), animation);
+
+    let mut geometry_sources = extra_model_sources(&extra_models, &geometry);
+    geometry_sources.insert(0, geometry.into());
+
     let job = RenderJob {
         scale,
+        alpha: config_to_alpha(alpha),
         root: RenderNode::RenderedImage(RenderedImage {
-            size: Size {width, height},
+            size,
             background,
-            camera: preset_to_camera(&camera, &file),
-            //TODO: Figure out how we want to allow lights to be configured
-            lights: RenderLights::Lights(Arc::new(vec![Arc::new(Light {
-                data: Arc::new(LightType::Directional {
-                    name: None,
-                    color: Rgb::white(),
-                    intensity: 1.0,
-                }),
-                world_transform: Mat4::rotation_x((-60.0f32).to_radians()),
-            })])),
+            camera,
+            lights,
+            max_lights,
             ambient_light: Rgb::white() * 0.5,
-            geometry,
+            geometry: geometry_sources,
             outline: config_to_outline(outline),
+            shadows: shadows.map(config_to_shadows),
+            highlights: config_to_highlights(rim_light, specular, materials, color_transfer),
+            color_transfer,
         }),
     };
 
     Ok(Task {
         output_path: path.resolve(base_dir),
         job,
+        // A pose is stored in its metadata file as an animation with a single frame
+        frames: vec![FrameInfo {
+            animation: "0".to_string(),
+            row: 0,
+            col: 0,
+            duration: None,
+            root_offset: None,
+            pivot,
+            markers,
+        }],
+        metadata_path: metadata_path.map(|path| path.resolve(base_dir)),
+        gifs: Vec::new(),
+        pivots,
+        cell_size: scaled_size(size, scale),
     })
 }
 
@@ -137,129 +327,324 @@ pub fn generate_spritesheet_task(
     sheet: config::Spritesheet,
     base_dir: &Path,
     file_cache: &mut WeakFileCache,
-) -> Result<Task, FileError> {
-    let config::Spritesheet {path, cell_width, cell_height, animations, scale, background} = sheet;
+) -> Result<Task, TaskError> {
+    let config::Spritesheet {
+        path,
+        metadata_path,
+        cell_width,
+        cell_height,
+        animations,
+        scale,
+        background,
+        color_transfer,
+        alpha,
+    } = sheet;
+    let color_transfer = config_to_color_transfer(color_transfer);
 
     // Flatten all of the animations into a single list of nodes, inserting empty nodes along the
     // way to fill any gaps in the grid
     let mut max_cols = 0;
     let mut grid = Vec::new();
-    for anim in animations {
-        let mut row = Vec::new();
+    let mut frame_infos = Vec::new();
+    let mut gifs = Vec::new();
+    let mut pivots = Vec::new();
+    // The frames of each animation are found by its name in the metadata file and GIFs
+    let mut anim_names = HashSet::new();
+    // Prevents options that only affect the metadata file from being silently ignored
+    let require_metadata = |option| match metadata_path {
+        Some(_) => Ok(()),
+        None => Err(TaskError::MissingMetadataPath {option}),
+    };
+    for (anim_index, anim) in animations.into_iter().enumerate() {
+        // Most animations are a single row, but an animation rendered for every scene has one row
+        // per scene
+        let mut rows = Vec::new();
 
-        let config::Animation {frames, frame_width, frame_height, camera, outline} = anim;
+        let config::Animation {
+            name: anim_name,
+            frames,
+            gif_path,
+            frame_width,
+            frame_height,
+            camera,
+            lights,
+            camera_follow,
+            outline,
+            extra_models,
+            shadows,
+            rim_light,
+            specular,
+            materials,
+            max_lights,
+            pivot,
+            attachment_points,
+            hitboxes,
+        } = anim;
+        let anim_name = anim_name.unwrap_or_else(|| anim_index.to_string());
+        if !anim_names.insert(anim_name.clone()) {
+            return Err(TaskError::DuplicateAnimationName {name: anim_name});
+        }
+        if let Some(gif_path) = gif_path {
+            gifs.push(GifOutput {path: gif_path.resolve(base_dir), animation: anim_name.clone()});
+        }
         let outline = config_to_outline(outline);
+        let shadows = shadows.map(config_to_shadows);
+        let highlights = config_to_highlights(rim_light, specular, materials, color_transfer);
+        let extra_models = open_extra_models(extra_models, base_dir, file_cache)?;
 
         let frame_size = Size {width: frame_width, height: frame_height};
+        let cell_size = Size {width: cell_width, height: cell_height};
+        if matches!(pivot, Some(config::Pivot {align_to: None, ..})) {
+            require_metadata("pivot")?;
+        }
+        // Returns the camera to draw the given frame with and the pivot of the frame, or None if
+        // the pivot is only found once the frame is drawn
+        let apply_pivot = |camera, file: &Arc<Mutex<File>>, scene: Option<&String>, animation: Option<&_>, frame, pivots: &mut Vec<_>| {
+            match &pivot {
+                Some(pivot) => match frame_pivot(pivot, camera, file, scene, animation, frame_size) {
+                    (camera, PivotPixel::Aligned(position)) => {
+                        (camera, Some(frame_to_cell(position, frame_size, cell_size, scale)))
+                    },
+                    (camera, PivotPixel::Projected(point)) => {
+                        pivots.push(PendingPivot {frame, camera: camera.clone(), point, frame_size, cell_size, scale});
+                        (camera, None)
+                    },
+                },
+                None => (camera, None),
+            }
+        };
+        let markers = |camera: &_, file: &_, scene: Option<&String>, animation: Option<&_>| {
+            frame_markers(&attachment_points, &hitboxes, camera, file, scene, animation, frame_size)
+                .map(|markers| markers.map_positions(|position| frame_to_cell(position, frame_size, cell_size, scale)))
+        };
+        let frame_cell = |file, scene: Option<String>, camera, animation: Option<AnimationQuery>| GridLayoutCell {
+            node: RenderNode::RenderedImage(RenderedImage {
+                size: frame_size,
+                background,
+                camera,
+                lights: config_to_lights(lights.as_ref(), &file, scene.as_ref(), animation.as_ref()),
+                max_lights,
+                ambient_light: Rgb::white() * 0.5,
+                geometry: {
+                    let geometry = FileQuery {
+                        query: GeometryQuery {
+                            models: GeometryFilter::Scene {name: scene},
+                            animation,
+                        },
+
+                        file,
+                    };
+
+                    let mut sources = extra_model_sources(&extra_models, &geometry);
+                    sources.insert(0, geometry.into());
+                    sources
+                },
+                outline: outline.clone(),
+                shadows: shadows.clone(),
+                highlights: highlights.clone(),
+                color_transfer,
+            }),
+            col_span: unsafe { NonZeroU32::new_unchecked(1) },
+            row_span: unsafe { NonZeroU32::new_unchecked(1) },
+        };
+
+        // Adds a frame of a glTF animation, sampled at the given position, to the end of a row
+        let mut push_gltf_frame = |
+            row: &mut Vec<_>,
+            row_index,
+            gltf_anim: &GltfAnimation,
+            scene: &Option<String>,
+            position,
+            duration,
+        | -> Result<(), TaskError> {
+            let GltfAnimation {file, name, layers, root_motion, export_offsets} = gltf_anim;
+            let query = AnimationQuery {
+                name: name.clone(),
+                layers: config_to_layers(layers, &position),
+                position,
+                root_motion: root_motion.clone(),
+            };
+
+            let root_offset = exported_root_offset(file, scene.as_ref(), &query, *export_offsets)?;
+            let frame_camera = preset_to_camera(&camera, file, scene.as_ref(), Some(&query));
+            let frame_camera = follow_camera(&frame_camera, &camera_follow, file, scene.as_ref(), &query);
+            let (frame_camera, pivot) = apply_pivot(frame_camera, file, scene.as_ref(), Some(&query), frame_infos.len(), &mut pivots);
+            frame_infos.push(FrameInfo {
+                animation: anim_name.clone(),
+                row: row_index,
+                col: row.len() as u32,
+                duration,
+                root_offset,
+                pivot,
+                markers: markers(&frame_camera, file, scene.as_ref(), Some(&query))?,
+            });
+            row.push(frame_cell(file.clone(), scene.clone(), frame_camera, Some(query)));
+
+            Ok(())
+        };
 
         use config::AnimationFrames::*;
         match frames {
-            GltfFrames {gltf, animation: name, start_time, end_time, steps} => {
+            GltfFrames {
+                gltf,
+                scene,
+                all_scenes,
+                animation: name,
+                start_time,
+                end_time,
+                steps,
+                fps,
+                easing,
+                loop_safe,
+                loop_offset,
+                playback,
+                holds,
+                layers,
+                root_motion,
+            } => {
+                if matches!(end_time, Some(end_time) if end_time < start_time) {
+                    return Err(TaskError::InvalidFrameSampling {
+                        reason: "`end_time` must not be before `start_time`".to_string(),
+                    });
+                }
+                if !loop_offset.is_finite() {
+                    return Err(TaskError::InvalidFrameSampling {
+                        reason: format!("`loop_offset` must be a finite number, got {}", loop_offset),
+                    });
+                }
+                validate_layers(&layers)?;
+                let file = file_cache.open_gltf(&gltf.resolve(base_dir))?;
+
+                let animation_end_time = || {
+                    let file = file.lock().expect("bug: file lock was poisoned");
+                    file.query_animation_end_time(name.as_deref())
+                };
+                let (steps, end_time, duration) = match (steps, fps) {
+                    (Some(steps), None) => {
+                        // The duration is only used for metadata, so it is fine if the end of the
+                        // animation can't be found here
+                        let duration = end_time.map(Ok).unwrap_or_else(animation_end_time).ok()
+                            .map(|end_time| step_duration(start_time, end_time, steps, loop_safe));
+                        (steps, end_time, duration)
+                    },
+
+                    (None, Some(fps)) => {
+                        let end_time = match end_time {
+                            Some(end_time) => end_time,
+                            None => animation_end_time()?,
+                        };
+                        let (steps, end_time) = fps_steps(start_time, end_time, fps, loop_safe)
+                            .ok_or_else(|| TaskError::InvalidFrameSampling {
+                                reason: format!("`fps` must be greater than zero and sample at most {} \
+                                    frames, got {}", MAX_FPS_STEPS, fps),
+                            })?;
+                        (steps, Some(end_time), Some(Milliseconds::from_msec(1000.0 / fps)))
+                    },
+
+                    (Some(_), Some(_)) |
+                    (None, None) => return Err(TaskError::InvalidFrameSampling {
+                        reason: "exactly one of `steps` or `fps` must be provided".to_string(),
+                    }),
+                };
+
+                let (root_motion, export_offsets) = config_to_root_motion(root_motion);
+                if export_offsets {
+                    require_metadata("root_motion.export_offsets")?;
+                }
+                let positions = step_positions(start_time, end_time, steps, easing, loop_safe, loop_offset);
+                let positions = arrange_frames(positions, playback, &holds);
+                let scenes = selected_scenes(&file, scene, all_scenes)?;
+                let gltf_anim = GltfAnimation {file, name, layers, root_motion, export_offsets};
+                for scene in scenes {
+                    let row_index = (grid.len() + rows.len()) as u32;
+                    let mut row = Vec::new();
+
+                    for position in positions.iter().cloned() {
+                        push_gltf_frame(&mut row, row_index, &gltf_anim, &scene, position, duration)?;
+                    }
+
+                    rows.push(row);
+                }
+            },
+
+            GltfFrameTimes {gltf, scene, all_scenes, animation: name, times, playback, holds, layers, root_motion} => {
+                validate_layers(&layers)?;
                 let file = file_cache.open_gltf(&gltf.resolve(base_dir))?;
-                let camera = preset_to_camera(&camera, &file);
-
-                //           | step       => weight
-                // steps = 1 | 0          => 0.0
-                // steps = 2 | 0, 1       => 0.0, 1.0
-                // steps = 3 | 0, 1, 2    => 0.0, 0.5, 1.0
-                // steps = 4 | 0, 1, 2, 3 => 0.0, 0.33, 0.66, 1.0
-                let steps = steps.get();
-                for step in 0..steps {
-                    let weight = step as f32 / max(steps - 1, 1) as f32;
-
-                    row.push(GridLayoutCell {
-                        node: RenderNode::RenderedImage(RenderedImage {
-                            size: frame_size,
-                            background,
-                            camera: camera.clone(),
-                            //TODO: Figure out how we want to allow lights to be configured
-                            lights: RenderLights::Lights(Arc::new(vec![Arc::new(Light {
-                                data: Arc::new(LightType::Directional {
-                                    name: None,
-                                    color: Rgb::white(),
-                                    intensity: 1.0,
-                                }),
-                                world_transform: Mat4::rotation_x((-60.0f32).to_radians()),
-                            })])),
-                            ambient_light: Rgb::white() * 0.5,
-                            geometry: FileQuery {
-                                query: GeometryQuery {
-                                    models: GeometryFilter::all_in_default_scene(),
-
-                                    animation: Some(AnimationQuery {
-                                        name: name.clone(),
-                                        position: match end_time {
-                                            Some(end_time) => AnimationPosition::Time(
-                                                Milliseconds::from_msec(lerp(&start_time.to_msec(), &end_time.to_msec(), &weight))
-                                            ),
-
-                                            None => AnimationPosition::RelativeTime {
-                                                start_time,
-                                                weight,
-                                            },
-                                        }
-                                    })
-                                },
-
-                                file: file.clone(),
-                            },
-                            outline: outline.clone(),
-                        }),
-                        col_span: unsafe { NonZeroU32::new_unchecked(1) },
-                        row_span: unsafe { NonZeroU32::new_unchecked(1) },
+
+                if let Some(index) = backwards_time(&times) {
+                    return Err(TaskError::InvalidFrameSampling {
+                        reason: format!("`times` must be in order, but time {} is before the time before it", index),
                     });
                 }
+                let frame_durations = time_durations(&times);
+
+                let (root_motion, export_offsets) = config_to_root_motion(root_motion);
+                if export_offsets {
+                    require_metadata("root_motion.export_offsets")?;
+                }
+                let frames = times.into_iter().map(AnimationPosition::Time).zip(frame_durations).collect();
+                let frames = arrange_frames(frames, playback, &holds);
+                let scenes = selected_scenes(&file, scene, all_scenes)?;
+                let gltf_anim = GltfAnimation {file, name, layers, root_motion, export_offsets};
+                for scene in scenes {
+                    let row_index = (grid.len() + rows.len()) as u32;
+                    let mut row = Vec::new();
+
+                    for (position, duration) in frames.iter().cloned() {
+                        push_gltf_frame(&mut row, row_index, &gltf_anim, &scene, position, duration)?;
+                    }
+
+                    rows.push(row);
+                }
             },
 
             Models(models) => {
+                let row_index = grid.len() as u32;
+                let mut row = Vec::new();
+
                 // Use each model as a frame in the animation
                 for model_path in models {
                     let file = file_cache.open(&model_path.resolve(base_dir))?;
-                    let camera = preset_to_camera(&camera, &file);
-
-                    row.push(GridLayoutCell {
-                        node: RenderNode::RenderedImage(RenderedImage {
-                            size: frame_size,
-                            background,
-                            camera,
-                            //TODO: Figure out how we want to allow lights to be configured
-                            lights: RenderLights::Lights(Arc::new(vec![Arc::new(Light {
-                                data: Arc::new(LightType::Directional {
-                                    name: None,
-                                    color: Rgb::white(),
-                                    intensity: 1.0,
-                                }),
-                                world_transform: Mat4::rotation_x((-60.0f32).to_radians()),
-                            })])),
-                            ambient_light: Rgb::white() * 0.5,
-                            geometry: FileQuery {
-                                query: GeometryQuery {
-                                    models: GeometryFilter::all_in_default_scene(),
-                                    // Use the default state of the scene
-                                    animation: None,
-                                },
-
-                                file,
-                            },
-                            outline: outline.clone(),
-                        }),
-                        col_span: unsafe { NonZeroU32::new_unchecked(1) },
-                        row_span: unsafe { NonZeroU32::new_unchecked(1) },
+                    let camera = preset_to_camera(&camera, &file, None, None);
+                    let (camera, pivot) = apply_pivot(camera, &file, None, None, frame_infos.len(), &mut pivots);
+
+                    // Use the default state of the scene
+                    frame_infos.push(FrameInfo {
+                        animation: anim_name.clone(),
+                        row: row_index,
+                        col: row.len() as u32,
+                        duration: None,
+                        root_offset: None,
+                        pivot,
+                        markers: markers(&camera, &file, None, None)?,
                     });
+                    row.push(frame_cell(file, None, camera, None));
                 }
+
+                rows.push(row);
             },
         }
-        max_cols = max(max_cols, row.len() as u32);
-        grid.push(row);
+
+        if rows.iter().all(Vec::is_empty) {
+            return Err(TaskError::EmptyAnimation {name: anim_name});
+        }
+        for row in rows {
+            max_cols = max(max_cols, row.len() as u32);
+            grid.push(row);
+        }
     }
 
+    // Every animation has at least one frame, so the grid is only empty if there are no animations
+    let rows = NonZeroU32::new(grid.len() as u32).ok_or(TaskError::EmptySpritesheet)?;
+    let cols = NonZeroU32::new(max_cols).ok_or(TaskError::EmptySpritesheet)?;
     let job = RenderJob {
         scale,
+        alpha: config_to_alpha(alpha),
         root: RenderNode::Layout(
             RenderLayout::Grid(
                 GridLayout {
-                    rows: NonZeroU32::new(grid.len() as u32).expect("Expected a non-zero amount of rows"),
-                    cols: NonZeroU32::new(max_cols).expect("Expected a non-zero amount of columns"),
+                    rows,
+                    cols,
                     cell_size: Size {
                         width: cell_width,
                         height: cell_height,
@@ -273,18 +658,153 @@ pub fn generate_spritesheet_task(
     Ok(Task {
         output_path: path.resolve(base_dir),
         job,
+        frames: frame_infos,
+        metadata_path: metadata_path.map(|path| path.resolve(base_dir)),
+        gifs,
+        pivots,
+        cell_size: scaled_size(Size {width: cell_width, height: cell_height}, scale),
     })
 }
 
-fn preset_to_camera(cam: &config::PresetCamera, file: &Arc<Mutex<File>>) -> RenderCamera {
+/// The parts of a glTF animation that are the same for every frame drawn from it
+struct GltfAnimation {
+    file: Arc<Mutex<File>>,
+    /// The name of the animation, or None if the default animation should be used
+    name: Option<String>,
+    layers: Vec<config::AnimationLayer>,
+    root_motion: Option<RootMotionLock>,
+    /// True if the offsets removed by the root motion lock should be written to the metadata file
+    export_offsets: bool,
+}
+
+/// Returns the configured camera, moved by the given animation query if it is in the model file
+///
+/// Cameras in the model file are looked for in the given scene unless the camera has its own
+/// scene configured.
+fn preset_to_camera(
+    cam: &config::PresetCamera,
+    file: &Arc<Mutex<File>>,
+    scene: Option<&String>,
+    animation: Option<&AnimationQuery>,
+) -> RenderCamera {
     use config::PresetCamera::*;
     match cam {
         &Perspective(persp) => config_to_camera(&persp.into()),
-        Named(named) => named_to_camera(named, file),
+        Named(named) => named_to_camera(named, file, scene, animation),
         Custom(cam) => config_to_camera(cam),
     }
 }
 
+/// Returns a camera that follows the configured node as it is moved by the given animation query
+fn follow_camera(
+    camera: &RenderCamera,
+    follow: &Option<config::CameraFollow>,
+    file: &Arc<Mutex<File>>,
+    scene: Option<&String>,
+    query: &AnimationQuery,
+) -> RenderCamera {
+    let config::CameraFollow {node, axes} = match follow {
+        Some(follow) => follow,
+        None => return camera.clone(),
+    };
+
+    RenderCamera::Follow(Box::new(FollowCamera {
+        camera: camera.clone(),
+        target: FileQuery {
+            query: NodeQuery {
+                name: node.clone(),
+                scene: scene.cloned(),
+                animation: Some(query.clone()),
+            },
+            file: file.clone(),
+        },
+        x: axes.contains(&config::Axis::X),
+        y: axes.contains(&config::Axis::Y),
+        z: axes.contains(&config::Axis::Z),
+    }))
+}
+
+/// An extra model whose file has been opened
+struct OpenedModel {
+    file: Arc<Mutex<File>>,
+    scene: Option<String>,
+    animation: Option<String>,
+    attach_to: Option<String>,
+    offset: Vec3,
+}
+
+fn open_extra_models(
+    models: Vec<config::ExtraModel>,
+    base_dir: &Path,
+    file_cache: &mut WeakFileCache,
+) -> Result<Vec<OpenedModel>, TaskError> {
+    models.into_iter().map(|model| {
+        let config::ExtraModel {model, scene, animation, attach_to, offset} = model;
+        let file = file_cache.open(&model.resolve(base_dir))?;
+        Ok(OpenedModel {file, scene, animation, attach_to, offset})
+    }).collect()
+}
+
+/// Returns the geometry of each extra model to draw along with the given main geometry
+///
+/// The animation of each model is sampled at the same position as the animation of the main
+/// geometry. If the main geometry is not animated, every model is drawn as loaded.
+fn extra_model_sources(models: &[OpenedModel], main: &FileQuery<GeometryQuery>) -> Vec<GeometrySource> {
+    let FileQuery {query: GeometryQuery {models: main_models, animation: main_animation}, file: main_file} = main;
+    let GeometryFilter::Scene {name: main_scene} = main_models;
+    let position = main_animation.as_ref().map(|anim| &anim.position);
+
+    models.iter().map(|model| {
+        let OpenedModel {file, scene, animation, attach_to, offset} = model;
+
+        let animation = match (animation, position) {
+            (Some(name), Some(position)) => Some(AnimationQuery {
+                name: Some(name.clone()),
+                position: position.clone(),
+                layers: Vec::new(),
+                root_motion: None,
+            }),
+            _ => None,
+        };
+
+        GeometrySource {
+            geometry: FileQuery {
+                query: GeometryQuery {
+                    models: GeometryFilter::Scene {name: scene.clone()},
+                    animation,
+                },
+                file: file.clone(),
+            },
+            transform: Mat4::translation_3d(*offset),
+            attach: attach_to.as_ref().map(|node| FileQuery {
+                query: NodeQuery {
+                    name: node.clone(),
+                    scene: main_scene.clone(),
+                    animation: main_animation.clone(),
+                },
+                file: main_file.clone(),
+            }),
+        }
+    }).collect()
+}
+
+/// Returns the scenes that should each be rendered as a row, where None is the default scene
+fn selected_scenes(
+    file: &Arc<Mutex<File>>,
+    scene: Option<String>,
+    all_scenes: bool,
+) -> Result<Vec<Option<String>>, TaskError> {
+    if !all_scenes {
+        return Ok(vec![scene]);
+    }
+    if scene.is_some() {
+        return Err(TaskError::ConflictingSceneSelection);
+    }
+
+    let file = file.lock().expect("bug: file lock was poisoned");
+    Ok(file.query_scene_names()?.into_iter().map(Some).collect())
+}
+
 fn config_to_camera(cam: &config::Camera) -> RenderCamera {
     let &config::Camera {eye, target, aspect_ratio, fov_y, near_z, far_z} = cam;
     let field_of_view_y = fov_y.into();
@@ -302,21 +822,297 @@ fn config_to_camera(cam: &config::Camera) -> RenderCamera {
     }))
 }
 
-fn named_to_camera(named: &config::NamedCamera, file: &Arc<Mutex<File>>) -> RenderCamera {
+fn named_to_camera(
+    named: &config::NamedCamera,
+    file: &Arc<Mutex<File>>,
+    default_scene: Option<&String>,
+    animation: Option<&AnimationQuery>,
+) -> RenderCamera {
     let config::NamedCamera {name, scene} = named;
 
     RenderCamera::Query(FileQuery {
         query: CameraQuery::Named {
             name: name.clone(),
-            scene: scene.clone(),
+            scene: scene.as_ref().or(default_scene).cloned(),
+            animation: animation.cloned(),
+        },
+
+        file: file.clone(),
+    })
+}
+
+/// Returns the configured lights, moved by the given animation query, or the default light if no
+/// lights were configured
+///
+/// Lights in the model file are looked for in the given scene unless the lights have their own
+/// scene configured.
+fn config_to_lights(
+    lights: Option<&config::ModelLights>,
+    file: &Arc<Mutex<File>>,
+    default_scene: Option<&String>,
+    animation: Option<&AnimationQuery>,
+) -> RenderLights {
+    let config::ModelLights {name, scene} = match lights {
+        Some(lights) => lights,
+        None => return RenderLights::Lights(Arc::new(vec![Arc::new(Light {
+            data: Arc::new(LightType::Directional {
+                name: None,
+                color: Rgb::white(),
+                intensity: 1.0,
+            }),
+            world_transform: Mat4::rotation_x((-60.0f32).to_radians()),
+        })])),
+    };
+
+    let scene = scene.as_ref().or(default_scene).cloned();
+    let animation = animation.cloned();
+    RenderLights::Query(FileQuery {
+        query: match name {
+            Some(name) => LightQuery::Named {name: name.clone(), scene, animation},
+            None => LightQuery::Scene {name: scene, animation},
         },
 
         file: file.clone(),
     })
 }
 
+/// Creates animation layers that are all sampled at the given position
+pub(crate) fn config_to_layers(layers: &[config::AnimationLayer], position: &AnimationPosition) -> Vec<AnimationLayer> {
+    layers.iter().map(|layer| {
+        let config::AnimationLayer {animation, weight, mask} = layer;
+
+        AnimationLayer {
+            name: animation.clone(),
+            position: position.clone(),
+            weight: *weight,
+            mask: mask.clone(),
+        }
+    }).collect()
+}
+
+/// Returns an error if any layer has a weight that can't be blended with
+fn validate_layers(layers: &[config::AnimationLayer]) -> Result<(), TaskError> {
+    match layers.iter().find(|layer| !(0.0..=1.0).contains(&layer.weight)) {
+        Some(layer) => Err(TaskError::InvalidLayerWeight {weight: layer.weight}),
+        None => Ok(()),
+    }
+}
+
+/// Returns the root motion lock for the query and whether the removed offsets should be exported
+pub(crate) fn config_to_root_motion(root_motion: Option<config::RootMotion>) -> (Option<RootMotionLock>, bool) {
+    match root_motion {
+        Some(config::RootMotion {node, axes, export_offsets}) => {
+            let lock = RootMotionLock {
+                node,
+                x: axes.contains(&config::Axis::X),
+                y: axes.contains(&config::Axis::Y),
+                z: axes.contains(&config::Axis::Z),
+            };
+
+            (Some(lock), export_offsets)
+        },
+
+        None => (None, false),
+    }
+}
+
+/// Returns the translation removed by the root motion lock of the query if `export` is true
+fn exported_root_offset(
+    file: &Arc<Mutex<File>>,
+    scene: Option<&String>,
+    query: &AnimationQuery,
+    export: bool,
+) -> Result<Option<Vec3>, QueryError> {
+    if !export {
+        return Ok(None);
+    }
+
+    let file = file.lock().expect("bug: file lock was poisoned");
+    file.query_root_motion(query, scene.map(String::as_str)).map(Some)
+}
+
+/// Converts a pixel position in a frame to a pixel position in the generated image, measured
+/// from the top left corner of the cell containing the frame
+fn frame_to_cell(position: Vec2, frame_size: Size, cell_size: Size, scale: NonZeroU32) -> Vec2 {
+    // Frames are centered in their cells (see GridLayout)
+    let center_offset = |cell: NonZeroU32, frame: NonZeroU32| (cell.get() / 2) as f32 - (frame.get() / 2) as f32;
+    let offset = Vec2 {
+        x: center_offset(cell_size.width, frame_size.width),
+        y: center_offset(cell_size.height, frame_size.height),
+    };
+
+    (position + offset) * scale.get() as f32
+}
+
+/// Returns the size of an image after it has been scaled by the given factor
+fn scaled_size(size: Size, scale: NonZeroU32) -> Size {
+    let Size {width, height} = size;
+    let scale_dimension = |value: NonZeroU32| NonZeroU32::new(value.get() * scale.get())
+        .expect("bug: scaling a non-zero size produced zero");
+
+    Size {width: scale_dimension(width), height: scale_dimension(height)}
+}
+
 fn config_to_outline(outline: config::Outline) -> Outline {
     let config::Outline {thickness, color} = outline;
 
     Outline {thickness, color}
 }
+
+fn config_to_shadows(shadows: config::Shadows) -> Shadows {
+    let config::Shadows {tint, extent, map_size, drop_shadow} = shadows;
+
+    let drop_shadow = drop_shadow.map(|drop_shadow| {
+        let config::DropShadow {style, ground_height, color, radius} = drop_shadow;
+
+        let style = match style {
+            config::DropShadowStyle::Projected => DropShadowStyle::Projected,
+            config::DropShadowStyle::Blob => DropShadowStyle::Blob {radius},
+        };
+
+        DropShadow {style, ground_height, color}
+    });
+
+    Shadows {tint, extent, map_size, drop_shadow}
+}
+
+fn config_to_alpha(alpha: config::AlphaOutput) -> AlphaOutput {
+    let config::AlphaOutput {mode, threshold, remove_fringe} = alpha;
+
+    let mode = match mode {
+        config::AlphaMode::Straight => AlphaMode::Straight,
+        config::AlphaMode::Premultiplied => AlphaMode::Premultiplied,
+        config::AlphaMode::Threshold => AlphaMode::Threshold(threshold),
+    };
+
+    AlphaOutput {mode, remove_fringe}
+}
+
+fn config_to_color_transfer(color_transfer: config::ColorTransfer) -> ColorTransfer {
+    match color_transfer {
+        config::ColorTransfer::Srgb => ColorTransfer::Srgb,
+        config::ColorTransfer::Linear => ColorTransfer::Linear,
+        config::ColorTransfer::Gamma(gamma) => ColorTransfer::Gamma(gamma),
+    }
+}
+
+/// The colors in the highlights are typed in the same encoding as the output image, so they are
+/// decoded using the given transfer function to be used in lighting
+fn config_to_highlights(
+    rim_light: Option<config::RimLight>,
+    specular: Option<config::Specular>,
+    materials: Vec<config::MaterialHighlights>,
+    color_transfer: ColorTransfer,
+) -> MaterialHighlights {
+    let config_to_rim_light = |rim_light| config_to_rim_light(rim_light, color_transfer);
+    let config_to_specular = |specular| config_to_specular(specular, color_transfer);
+
+    let default = Highlights {
+        rim_light: rim_light.map(config_to_rim_light),
+        specular: specular.map(config_to_specular),
+    };
+
+    let materials = materials.into_iter().map(|material| {
+        let config::MaterialHighlights {name, rim_light, specular} = material;
+
+        // Anything not configured for the material is taken from the default highlights
+        let highlights = Highlights {
+            rim_light: rim_light.map(config_to_rim_light).or_else(|| default.rim_light.clone()),
+            specular: specular.map(config_to_specular).or_else(|| default.specular.clone()),
+        };
+
+        (name, highlights)
+    }).collect();
+
+    MaterialHighlights {default, materials}
+}
+
+fn config_to_rim_light(rim_light: config::RimLight, color_transfer: ColorTransfer) -> RimLight {
+    let config::RimLight {color, width, threshold} = rim_light;
+
+    RimLight {color: color_transfer.decode_rgb(color), width, threshold}
+}
+
+fn config_to_specular(specular: config::Specular, color_transfer: ColorTransfer) -> Specular {
+    let config::Specular {color, shininess, threshold} = specular;
+
+    Specular {
+        color: color.map(|color| color_transfer.decode_rgb(color)),
+        shininess,
+        threshold,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a spritesheet that draws the given animations of the bigboi sample
+    fn bigboi_sheet(animations: &str) -> config::Spritesheet {
+        let conf_str = format!(r#"
+            [[spritesheets]]
+            path = "spritesheet.png"
+            cell_width = 64
+            cell_height = 64
+            {}
+        "#, animations);
+        let mut config: config::TaskConfig = toml::from_str(&conf_str).unwrap();
+        config.spritesheets.remove(0)
+    }
+
+    fn generate(sheet: config::Spritesheet) -> Result<Task, TaskError> {
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("samples/bigboi");
+        generate_spritesheet_task(sheet, &base_dir, &mut WeakFileCache::default())
+    }
+
+    #[test]
+    fn empty_spritesheets() {
+        let sheet = bigboi_sheet("animations = []");
+        assert!(matches!(generate(sheet), Err(TaskError::EmptySpritesheet)));
+
+        for frames in &["{ gltf = \"bigboi.gltf\", times = [] }", "[]"] {
+            let sheet = bigboi_sheet(&format!(r#"
+                [[spritesheets.animations]]
+                name = "empty"
+                frames = {}
+                frame_width = 64
+                frame_height = 64
+                camera = {{ name = "front" }}
+            "#, frames));
+            match generate(sheet) {
+                Err(TaskError::EmptyAnimation {name}) => assert_eq!(name, "empty"),
+                result => panic!("expected an empty animation, got {:?}", result.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn backwards_frame_times() {
+        let sheet = bigboi_sheet(r#"
+            [[spritesheets.animations]]
+            frames = { gltf = "bigboi.gltf", times = [500, 100, 300] }
+            frame_width = 64
+            frame_height = 64
+            camera = { name = "front" }
+        "#);
+        assert!(matches!(generate(sheet), Err(TaskError::InvalidFrameSampling {..})));
+    }
+
+    #[test]
+    fn rejects_what_check_rejects() {
+        let animation = |frames: &str| bigboi_sheet(&format!(r#"
+            [[spritesheets.animations]]
+            frames = {}
+            frame_width = 64
+            frame_height = 64
+            camera = {{ name = "front" }}
+        "#, frames));
+
+        let sheet = animation("{ gltf = \"bigboi.gltf\", start_time = 500, end_time = 100, steps = 2 }");
+        assert!(matches!(generate(sheet), Err(TaskError::InvalidFrameSampling {..})));
+        let sheet = animation("{ gltf = \"bigboi.gltf\", steps = 2, loop_offset = nan }");
+        assert!(matches!(generate(sheet), Err(TaskError::InvalidFrameSampling {..})));
+        let sheet = animation("{ gltf = \"bigboi.gltf\", times = [0], layers = [{ animation = \"walk\", weight = 1.5 }] }");
+        assert!(matches!(generate(sheet), Err(TaskError::InvalidLayerWeight {..})));
+    }
+}
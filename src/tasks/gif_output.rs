@@ -0,0 +1,111 @@
+use std::io;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::convert::TryFrom;
+
+use gif::{Encoder, Frame, Repeat, DisposalMethod, SetParameter};
+use image::{RgbaImage, GenericImageView};
+
+use crate::renderer::Size;
+
+use super::FrameInfo;
+
+/// How long a frame is shown for (in ms) if its duration is not known
+const DEFAULT_FRAME_DURATION: f32 = 100.0;
+
+/// An animated GIF of one of the animations in a generated image
+#[derive(Debug, Clone)]
+pub struct GifOutput {
+    /// The absolute path to output the GIF
+    pub path: PathBuf,
+    /// The name of the animation whose frames are played by the GIF
+    pub animation: String,
+}
+
+/// Writes the given frames of a generated image to an animated GIF that loops forever
+///
+/// Each frame is cut out of its cell in the image and shown for its duration.
+pub fn write_gif<'a>(
+    path: &Path,
+    image: &RgbaImage,
+    cell_size: Size,
+    frames: impl Iterator<Item=&'a FrameInfo>,
+) -> Result<(), io::Error> {
+    let Size {width, height} = cell_size;
+    let (width, height) = (width.get(), height.get());
+    let too_large = |_| io::Error::new(io::ErrorKind::InvalidInput,
+        "GIF frames must be at most 65535 pixels wide and tall");
+    let gif_width = u16::try_from(width).map_err(too_large)?;
+    let gif_height = u16::try_from(height).map_err(too_large)?;
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = Encoder::new(file, gif_width, gif_height, &[])?;
+    encoder.set(Repeat::Infinite)?;
+
+    for frame in frames {
+        let &FrameInfo {row, col, duration, ..} = frame;
+
+        let mut pixels = image.view(col * width, row * height, width, height).to_image().into_raw();
+        let mut gif_frame = Frame::from_rgba(gif_width, gif_height, &mut pixels);
+        // GIF delays are measured in hundredths of a second
+        let duration = duration.map(|duration| duration.to_msec()).unwrap_or(DEFAULT_FRAME_DURATION);
+        gif_frame.delay = (duration / 10.0).round().max(1.0).min(u16::MAX as f32) as u16;
+        // Clear each frame before the next one is drawn so that the previous frame does not show
+        // through the transparent pixels of the next frame
+        gif_frame.dispose = DisposalMethod::Background;
+        encoder.write_frame(&gif_frame)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::num::NonZeroU32;
+
+    use image::{Rgba, AnimationDecoder, gif::GifDecoder};
+
+    use crate::math::Milliseconds;
+    use crate::tasks::FrameMarkers;
+
+    #[test]
+    fn frames_are_cut_from_their_cells() {
+        // A 2x2 grid of 3x2 cells, each filled with a different color
+        let colors = [
+            [Rgba([255, 0, 0, 255]), Rgba([0, 255, 0, 255])],
+            [Rgba([0, 0, 255, 255]), Rgba([0, 0, 0, 0])],
+        ];
+        let image = RgbaImage::from_fn(6, 4, |x, y| colors[(y / 2) as usize][(x / 3) as usize]);
+        let cell_size = Size {width: NonZeroU32::new(3).unwrap(), height: NonZeroU32::new(2).unwrap()};
+
+        let frame = |row, col, duration: Option<f32>| FrameInfo {
+            animation: "0".to_string(),
+            row,
+            col,
+            duration: duration.map(Milliseconds::from_msec),
+            root_offset: None,
+            pivot: None,
+            markers: FrameMarkers::default(),
+        };
+        let frames = vec![frame(0, 1, Some(83.3)), frame(1, 0, None), frame(0, 0, Some(250.0))];
+
+        let path = std::env::temp_dir().join("spritec-frames-are-cut-from-their-cells.gif");
+        write_gif(&path, &image, cell_size, frames.iter()).unwrap();
+        let decoder = GifDecoder::new(File::open(&path).unwrap()).unwrap();
+        let decoded = decoder.into_frames().collect_frames().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let actual: Vec<_> = decoded.iter()
+            .map(|frame| (*frame.buffer().get_pixel(1, 1), frame.delay().numer_denom_ms()))
+            .collect();
+        assert_eq!(actual, vec![
+            (colors[0][1], (80, 1)),
+            (colors[1][0], (100, 1)),
+            (colors[0][0], (250, 1)),
+        ]);
+        assert!(decoded.iter().all(|frame| frame.buffer().dimensions() == (3, 2)));
+    }
+}
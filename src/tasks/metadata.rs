@@ -0,0 +1,158 @@
+use std::io;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::math::{Vec2, Vec3, Vec4};
+use crate::config::{AttachmentPoint, Hitbox};
+use crate::query3d::{File, QueryError, QueryBackend, AnimationQuery, NodeQuery};
+use crate::renderer::{RenderCamera, Size};
+
+use super::FrameInfo;
+
+/// The attachment points and hitboxes of a single frame, measured in pixels
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FrameMarkers {
+    /// The position of each attachment point, by name
+    pub attachment_points: BTreeMap<String, Vec2>,
+    /// The bounding box of each hitbox, by name
+    pub hitboxes: BTreeMap<String, PixelRect>,
+}
+
+impl FrameMarkers {
+    /// Moves every point and the corners of every box to the positions returned by `f`
+    pub fn map_positions(self, f: impl Fn(Vec2) -> Vec2) -> Self {
+        let Self {attachment_points, hitboxes} = self;
+
+        Self {
+            attachment_points: attachment_points.into_iter()
+                .map(|(name, position)| (name, f(position)))
+                .collect(),
+            hitboxes: hitboxes.into_iter()
+                .filter_map(|(name, rect)| {
+                    let PixelRect {x, y, width, height} = rect;
+                    let corners = vec![f(Vec2 {x, y}), f(Vec2 {x: x + width, y: y + height})];
+                    PixelRect::from_points(corners).map(|rect| (name, rect))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A rectangle measured in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PixelRect {
+    /// The left edge of the rectangle
+    pub x: f32,
+    /// The top edge of the rectangle
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl PixelRect {
+    /// Returns the smallest rectangle that contains every point, or None if there are no points
+    pub fn from_points(points: impl IntoIterator<Item=Vec2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (Vec2::partial_min(min, point), Vec2::partial_max(max, point))
+        });
+
+        Some(Self {
+            x: min.x,
+            y: min.y,
+            width: max.x - min.x,
+            height: max.y - min.y,
+        })
+    }
+}
+
+/// The contents of the metadata file written alongside a generated image
+#[derive(Debug, Serialize)]
+struct Metadata<'a> {
+    /// The width of each cell of the generated image in pixels
+    cell_width: u32,
+    /// The height of each cell of the generated image in pixels
+    cell_height: u32,
+    /// The frames of each animation, in the order they were drawn
+    animations: BTreeMap<&'a str, Vec<&'a FrameInfo>>,
+}
+
+/// Projects the attachment points and hitboxes into a frame of the given size drawn with the
+/// given camera, measuring them from the top left corner of the frame
+pub fn frame_markers(
+    attachment_points: &[AttachmentPoint],
+    hitboxes: &[Hitbox],
+    camera: &RenderCamera,
+    file: &Arc<Mutex<File>>,
+    scene: Option<&String>,
+    animation: Option<&AnimationQuery>,
+    size: Size,
+) -> Result<FrameMarkers, QueryError> {
+    if attachment_points.is_empty() && hitboxes.is_empty() {
+        return Ok(FrameMarkers::default());
+    }
+
+    let camera = camera.fetch_camera()?;
+    let file = file.lock().expect("bug: file lock was poisoned");
+    let node_query = |node: &String| NodeQuery {
+        name: node.clone(),
+        scene: scene.cloned(),
+        animation: animation.cloned(),
+    };
+
+    let mut markers = FrameMarkers::default();
+    for point in attachment_points {
+        let AttachmentPoint {name, node, offset} = point;
+
+        let transform = file.query_node_transform(&node_query(node))?;
+        let position = Vec3::from(transform * Vec4::from_point(*offset));
+        let pixel = camera.project_to_pixels(position, size);
+        markers.attachment_points.insert(name.clone(), pixel);
+    }
+
+    for hitbox in hitboxes {
+        let Hitbox {name, node} = hitbox;
+
+        let positions = file.query_mesh_positions(&node_query(node))?;
+        let pixels = positions.into_iter().map(|pos| camera.project_to_pixels(pos, size));
+        if let Some(rect) = PixelRect::from_points(pixels) {
+            markers.hitboxes.insert(name.clone(), rect);
+        }
+    }
+
+    Ok(markers)
+}
+
+/// Writes the frames of a generated image to a JSON file, grouped by the animation they are from
+pub fn write_metadata(path: &Path, cell_size: Size, frames: &[FrameInfo]) -> Result<(), io::Error> {
+    let mut animations = BTreeMap::new();
+    for frame in frames {
+        animations.entry(frame.animation.as_str()).or_insert_with(Vec::new).push(frame);
+    }
+
+    let metadata = Metadata {
+        cell_width: cell_size.width.get(),
+        cell_height: cell_size.height.get(),
+        animations,
+    };
+
+    let json = serde_json::to_string_pretty(&metadata)?;
+    fs::write(path, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_contains_every_point() {
+        let points = vec![Vec2::new(4.0, 1.0), Vec2::new(-2.0, 3.0), Vec2::new(1.0, 7.5)];
+        assert_eq!(PixelRect::from_points(points), Some(PixelRect {x: -2.0, y: 1.0, width: 6.0, height: 6.5}));
+        assert_eq!(PixelRect::from_points(Vec::new()), None);
+    }
+}
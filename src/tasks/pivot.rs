@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+use std::num::NonZeroU32;
+
+use crate::math::Vec2;
+use crate::config::Pivot;
+use crate::query3d::{File, QueryError, AnimationQuery, NodeQuery};
+use crate::renderer::{RenderCamera, PivotCamera, NodePoint, FileQuery, Size};
+
+use super::frame_to_cell;
+
+/// Returns the point that the pivot is at in a frame of an animation
+fn pivot_point(
+    pivot: &Pivot,
+    file: &Arc<Mutex<File>>,
+    scene: Option<&String>,
+    animation: Option<&AnimationQuery>,
+) -> NodePoint {
+    let Pivot {node, offset, align_to: _} = pivot;
+
+    NodePoint {
+        offset: *offset,
+        attach: node.as_ref().map(|node| FileQuery {
+            query: NodeQuery {
+                name: node.clone(),
+                scene: scene.cloned(),
+                animation: animation.cloned(),
+            },
+
+            file: file.clone(),
+        }),
+    }
+}
+
+/// The pixel position of the pivot in a frame, measured from the top left corner of the frame
+#[derive(Debug, Clone)]
+pub enum PivotPixel {
+    /// The frame is shifted so that the pivot is drawn at this position
+    Aligned(Vec2),
+    /// The pivot is drawn wherever the camera of the frame puts this point, which is only found
+    /// when the frame is drawn
+    Projected(NodePoint),
+}
+
+/// Shifts the camera of a frame if the pivot should be drawn at a fixed position
+///
+/// Returns the camera to draw the frame with and the pixel position of the pivot in the frame.
+/// Nothing is queried here so that the queries run on the thread that draws the frame.
+pub fn frame_pivot(
+    pivot: &Pivot,
+    camera: RenderCamera,
+    file: &Arc<Mutex<File>>,
+    scene: Option<&String>,
+    animation: Option<&AnimationQuery>,
+    size: Size,
+) -> (RenderCamera, PivotPixel) {
+    let point = pivot_point(pivot, file, scene, animation);
+
+    match pivot.align_to {
+        Some(align_to) => {
+            let camera = RenderCamera::Pivot(Box::new(PivotCamera {camera, pivot: point, align_to, size}));
+            (camera, PivotPixel::Aligned(align_to))
+        },
+
+        None => (camera, PivotPixel::Projected(point)),
+    }
+}
+
+/// A pivot whose pixel position is found when the task containing its frame is drawn
+#[derive(Debug)]
+pub struct PendingPivot {
+    /// The index of the frame in the frames of the task
+    pub frame: usize,
+    /// The camera that the frame is drawn with
+    pub camera: RenderCamera,
+    /// The point that the pivot is at
+    pub point: NodePoint,
+    /// The size of the frame
+    pub frame_size: Size,
+    /// The size of the cell containing the frame, before it is scaled
+    pub cell_size: Size,
+    /// The scale factor applied to the generated image
+    pub scale: NonZeroU32,
+}
+
+impl PendingPivot {
+    /// Returns the pixel position of the pivot in the generated image, measured from the top left
+    /// corner of the cell containing the frame
+    pub fn fetch_position(&self) -> Result<Vec2, QueryError> {
+        let &Self {frame: _, ref camera, ref point, frame_size, cell_size, scale} = self;
+
+        let position = camera.fetch_camera()?.project_to_pixels(point.fetch_position()?, frame_size);
+        Ok(frame_to_cell(position, frame_size, cell_size, scale))
+    }
+}
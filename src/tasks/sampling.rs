@@ -0,0 +1,354 @@
+use std::cmp::max;
+use std::num::NonZeroU32;
+
+use interpolation::lerp;
+
+use crate::math::Milliseconds;
+use crate::config::{Easing, Playback, Hold};
+use crate::query3d::AnimationPosition;
+
+/// Returns the position in the animation of each of the given number of steps
+///
+/// If `end_time` is None, the steps end at the last keyframe of the animation.
+pub fn step_positions(
+    start_time: Milliseconds,
+    end_time: Option<Milliseconds>,
+    steps: NonZeroU32,
+    easing: Easing,
+    loop_safe: bool,
+    loop_offset: f32,
+) -> Vec<AnimationPosition> {
+    // Without loop_safe, the last step lands exactly on the end time:
+    //
+    //           | step       => weight
+    // steps = 1 | 0          => 0.0
+    // steps = 2 | 0, 1       => 0.0, 1.0
+    // steps = 3 | 0, 1, 2    => 0.0, 0.5, 1.0
+    // steps = 4 | 0, 1, 2, 3 => 0.0, 0.33, 0.66, 1.0
+    //
+    // With loop_safe, the end time is skipped since it is the same as the start time:
+    //
+    // steps = 4 | 0, 1, 2, 3 => 0.0, 0.25, 0.5, 0.75
+    let divisor = step_divisor(steps, loop_safe);
+
+    (0..steps.get()).map(|step| {
+        let weight = ease(easing, step as f32 / divisor as f32) + loop_offset;
+        // Only wrap around when there is an offset so that the end time can still be sampled
+        let looped = loop_offset != 0.0;
+
+        match end_time {
+            Some(end_time) => {
+                let weight = if looped { weight.rem_euclid(1.0) } else { weight };
+                AnimationPosition::Time(
+                    Milliseconds::from_msec(lerp(&start_time.to_msec(), &end_time.to_msec(), &weight))
+                )
+            },
+
+            None if looped => AnimationPosition::LoopedTime {start_time, weight},
+            None => AnimationPosition::RelativeTime {start_time, weight},
+        }
+    }).collect()
+}
+
+/// Returns the time between each of the given number of steps
+pub fn step_duration(
+    start_time: Milliseconds,
+    end_time: Milliseconds,
+    steps: NonZeroU32,
+    loop_safe: bool,
+) -> Milliseconds {
+    let span = end_time.to_msec() - start_time.to_msec();
+    Milliseconds::from_msec(span / step_divisor(steps, loop_safe) as f32)
+}
+
+/// The most steps that can be sampled at a given frame rate
+pub const MAX_FPS_STEPS: u32 = 10_000;
+
+/// Returns the number of steps needed to sample from the start time to the end time at the given
+/// frame rate, along with the end time to pass to `step_positions` so that the steps are exactly
+/// one frame apart
+///
+/// If the end time is not a whole number of frames after the start time, the last frame is the
+/// one just before the end time. With `loop_safe`, the last frame is the one just before the
+/// frame that would land on (or after) the end time.
+///
+/// Returns None if the frame rate is not a finite number greater than zero or if more than
+/// `MAX_FPS_STEPS` steps would be needed.
+pub fn fps_steps(
+    start_time: Milliseconds,
+    end_time: Milliseconds,
+    fps: f32,
+    loop_safe: bool,
+) -> Option<(NonZeroU32, Milliseconds)> {
+    // Allows for some floating point error when the end time is a whole number of frames away
+    const EPSILON: f32 = 0.001;
+
+    if !(fps.is_finite() && fps > 0.0) {
+        return None;
+    }
+
+    let frame_time = 1000.0 / fps;
+    let frames = (end_time.to_msec() - start_time.to_msec()) / frame_time;
+    if frames.is_nan() || frames >= MAX_FPS_STEPS as f32 {
+        return None;
+    }
+
+    let (steps, intervals) = if loop_safe {
+        let steps = max((frames - EPSILON).ceil() as u32, 1);
+        (steps, steps)
+    } else {
+        let steps = (frames + EPSILON).floor().max(0.0) as u32 + 1;
+        (steps, steps - 1)
+    };
+
+    let steps = NonZeroU32::new(steps).expect("bug: there should always be at least one step");
+    let end_time = Milliseconds::from_msec(start_time.to_msec() + intervals as f32 * frame_time);
+    Some((steps, end_time))
+}
+
+/// Returns the index of the first time that is before the time preceding it, or None if the
+/// times are in order
+pub fn backwards_time(times: &[Milliseconds]) -> Option<usize> {
+    times.windows(2).position(|pair| pair[1] < pair[0]).map(|index| index + 1)
+}
+
+/// Returns how long each frame is shown for when the frames are drawn at the given times
+///
+/// Each frame is shown until the time of the next frame. The last frame has no next frame, so it
+/// is shown for as long as the frame before it. The times must be in order (see `backwards_time`).
+pub fn time_durations(times: &[Milliseconds]) -> Vec<Option<Milliseconds>> {
+    let mut durations: Vec<_> = times.windows(2)
+        .map(|pair| Some(Milliseconds::from_msec(pair[1].to_msec() - pair[0].to_msec())))
+        .collect();
+    durations.push(durations.last().copied().flatten());
+    durations
+}
+
+fn step_divisor(steps: NonZeroU32, loop_safe: bool) -> u32 {
+    let steps = steps.get();
+    if loop_safe { steps } else { max(steps - 1, 1) }
+}
+
+/// Maps a value between 0.0 and 1.0 to the eased value between 0.0 and 1.0
+fn ease(easing: Easing, x: f32) -> f32 {
+    use Easing::*;
+    match easing {
+        Linear => x,
+        EaseIn => x * x,
+        EaseOut => 1.0 - (1.0 - x) * (1.0 - x),
+        EaseInOut => if x < 0.5 {
+            2.0 * x * x
+        } else {
+            1.0 - (2.0 - 2.0 * x).powi(2) / 2.0
+        },
+    }
+}
+
+/// Puts the sampled frames in the order given by `playback` and then repeats any held frames
+///
+/// Holds for frames that do not exist are ignored.
+pub fn arrange_frames<T: Clone>(mut frames: Vec<T>, playback: Playback, holds: &[Hold]) -> Vec<T> {
+    use Playback::*;
+    match playback {
+        Forward => {},
+        Reverse => frames.reverse(),
+        PingPong => {
+            // Skip the first and last frames on the way back so they are not drawn twice in a row
+            // when the animation loops
+            let len = frames.len();
+            if len > 2 {
+                let back: Vec<_> = frames[1..len-1].iter().rev().cloned().collect();
+                frames.extend(back);
+            }
+        },
+    }
+
+    let mut arranged = Vec::with_capacity(frames.len());
+    for (i, frame) in frames.into_iter().enumerate() {
+        let extra: u32 = holds.iter()
+            .filter(|hold| hold.frame as usize == i)
+            .map(|hold| hold.count.get() - 1)
+            .sum();
+        for _ in 0..extra {
+            arranged.push(frame.clone());
+        }
+        arranged.push(frame);
+    }
+
+    arranged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(n: u32) -> NonZeroU32 {
+        NonZeroU32::new(n).unwrap()
+    }
+
+    fn msec(time: f32) -> Milliseconds {
+        Milliseconds::from_msec(time)
+    }
+
+    /// Returns the time of each position, panicking if any position is not a time
+    fn times(positions: &[AnimationPosition]) -> Vec<f32> {
+        positions.iter().map(|pos| match pos {
+            AnimationPosition::Time(time) => time.to_msec(),
+            _ => panic!("expected a time, got {:?}", pos),
+        }).collect()
+    }
+
+    /// Returns the weight of each position, panicking if any position is not relative
+    fn weights(positions: &[AnimationPosition], expect_looped: bool) -> Vec<f32> {
+        positions.iter().map(|pos| match *pos {
+            AnimationPosition::RelativeTime {weight, ..} if !expect_looped => weight,
+            AnimationPosition::LoopedTime {weight, ..} if expect_looped => weight,
+            _ => panic!("expected looped to be {}, got {:?}", expect_looped, pos),
+        }).collect()
+    }
+
+    #[test]
+    fn loop_safe_divisor() {
+        assert_eq!(step_divisor(steps(1), false), 1);
+        assert_eq!(step_divisor(steps(4), false), 3);
+        assert_eq!(step_divisor(steps(1), true), 1);
+        assert_eq!(step_divisor(steps(4), true), 4);
+    }
+
+    #[test]
+    fn positions_with_end_time() {
+        let positions = step_positions(msec(100.0), Some(msec(500.0)), steps(5), Easing::Linear, false, 0.0);
+        assert_eq!(times(&positions), vec![100.0, 200.0, 300.0, 400.0, 500.0]);
+
+        let positions = step_positions(msec(100.0), Some(msec(500.0)), steps(4), Easing::Linear, true, 0.0);
+        assert_eq!(times(&positions), vec![100.0, 200.0, 300.0, 400.0]);
+
+        let positions = step_positions(msec(0.0), Some(msec(400.0)), steps(4), Easing::Linear, true, 0.5);
+        assert_eq!(times(&positions), vec![200.0, 300.0, 0.0, 100.0]);
+
+        let positions = step_positions(msec(0.0), Some(msec(400.0)), steps(1), Easing::Linear, false, 0.0);
+        assert_eq!(times(&positions), vec![0.0]);
+    }
+
+    #[test]
+    fn positions_without_end_time() {
+        let positions = step_positions(msec(0.0), None, steps(3), Easing::Linear, false, 0.0);
+        assert_eq!(weights(&positions, false), vec![0.0, 0.5, 1.0]);
+
+        let positions = step_positions(msec(0.0), None, steps(4), Easing::Linear, true, 0.0);
+        assert_eq!(weights(&positions, false), vec![0.0, 0.25, 0.5, 0.75]);
+
+        // The weights of looped positions are wrapped when the animation is sampled
+        let positions = step_positions(msec(0.0), None, steps(4), Easing::Linear, true, 0.5);
+        assert_eq!(weights(&positions, true), vec![0.5, 0.75, 1.0, 1.25]);
+
+        let positions = step_positions(msec(0.0), None, steps(5), Easing::EaseIn, false, 0.0);
+        assert_eq!(weights(&positions, false), vec![0.0, 0.0625, 0.25, 0.5625, 1.0]);
+    }
+
+    /// Returns the steps and end time from `fps_steps`, with the end time rounded to the nearest
+    /// hundredth of a millisecond
+    fn fps_steps_rounded(end_time: f32, fps: f32, loop_safe: bool) -> Option<(u32, f32)> {
+        fps_steps(msec(0.0), msec(end_time), fps, loop_safe)
+            .map(|(steps, end_time)| (steps.get(), (end_time.to_msec() * 100.0).round() / 100.0))
+    }
+
+    #[test]
+    fn steps_at_frame_rate() {
+        // A whole number of frames
+        assert_eq!(fps_steps_rounded(1000.0, 12.0, false), Some((13, 1000.0)));
+        assert_eq!(fps_steps_rounded(1000.0, 12.0, true), Some((12, 1000.0)));
+
+        // Not a whole number of frames, so the last frame is just before the end time
+        assert_eq!(fps_steps_rounded(1050.0, 10.0, false), Some((11, 1000.0)));
+        assert_eq!(fps_steps_rounded(1050.0, 10.0, true), Some((11, 1100.0)));
+
+        // Shorter than a single frame
+        assert_eq!(fps_steps_rounded(50.0, 10.0, false), Some((1, 0.0)));
+        assert_eq!(fps_steps_rounded(50.0, 10.0, true), Some((1, 100.0)));
+
+        // The start time offsets the end time
+        let (steps, end_time) = fps_steps(msec(250.0), msec(750.0), 4.0, false).unwrap();
+        assert_eq!((steps.get(), end_time.to_msec()), (3, 750.0));
+    }
+
+    #[test]
+    fn invalid_frame_rates() {
+        assert_eq!(fps_steps_rounded(1000.0, 0.0, false), None);
+        assert_eq!(fps_steps_rounded(1000.0, -12.0, false), None);
+        assert_eq!(fps_steps_rounded(1000.0, f32::NAN, false), None);
+        assert_eq!(fps_steps_rounded(1000.0, f32::INFINITY, true), None);
+        assert_eq!(fps_steps_rounded(1000.0, 1e9, true), None);
+        assert_eq!(fps_steps_rounded(f32::INFINITY, 12.0, false), None);
+        assert!(fps_steps_rounded(1000.0, MAX_FPS_STEPS as f32 - 1.0, true).is_some());
+    }
+
+    #[test]
+    fn durations_between_steps() {
+        assert_eq!(step_duration(msec(0.0), msec(1000.0), steps(5), false).to_msec(), 250.0);
+        assert_eq!(step_duration(msec(0.0), msec(1000.0), steps(5), true).to_msec(), 200.0);
+        assert_eq!(step_duration(msec(200.0), msec(500.0), steps(1), false).to_msec(), 300.0);
+        assert_eq!(step_duration(msec(200.0), msec(500.0), steps(1), true).to_msec(), 300.0);
+
+        // Matches the frame rate that the steps were derived from
+        let (steps, end_time) = fps_steps(msec(0.0), msec(1050.0), 10.0, false).unwrap();
+        assert_eq!(step_duration(msec(0.0), end_time, steps, false).to_msec(), 100.0);
+        let (steps, end_time) = fps_steps(msec(0.0), msec(1050.0), 10.0, true).unwrap();
+        assert_eq!(step_duration(msec(0.0), end_time, steps, true).to_msec(), 100.0);
+    }
+
+    #[test]
+    fn ordered_frame_times() {
+        let times = |times: &[f32]| times.iter().copied().map(msec).collect::<Vec<_>>();
+
+        assert_eq!(backwards_time(&times(&[100.0, 100.0, 300.0])), None);
+        assert_eq!(backwards_time(&times(&[500.0, 100.0, 300.0])), Some(1));
+        assert_eq!(backwards_time(&times(&[0.0, 200.0, 100.0])), Some(2));
+        assert_eq!(backwards_time(&[]), None);
+
+        let durations = time_durations(&times(&[0.0, 100.0, 300.0]));
+        assert_eq!(durations, vec![Some(msec(100.0)), Some(msec(200.0)), Some(msec(200.0))]);
+        // A single frame has no other frame to measure its duration from
+        assert_eq!(time_durations(&times(&[100.0])), vec![None]);
+    }
+
+    #[test]
+    fn easing_curves() {
+        let xs = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let eased = |easing| xs.iter().map(|&x| ease(easing, x)).collect::<Vec<_>>();
+
+        assert_eq!(eased(Easing::Linear), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(eased(Easing::EaseIn), vec![0.0, 0.0625, 0.25, 0.5625, 1.0]);
+        assert_eq!(eased(Easing::EaseOut), vec![0.0, 0.4375, 0.75, 0.9375, 1.0]);
+        assert_eq!(eased(Easing::EaseInOut), vec![0.0, 0.125, 0.5, 0.875, 1.0]);
+    }
+
+    #[test]
+    fn playback_order() {
+        let frames = vec![0, 1, 2, 3];
+        assert_eq!(arrange_frames(frames.clone(), Playback::Forward, &[]), vec![0, 1, 2, 3]);
+        assert_eq!(arrange_frames(frames.clone(), Playback::Reverse, &[]), vec![3, 2, 1, 0]);
+        assert_eq!(arrange_frames(frames, Playback::PingPong, &[]), vec![0, 1, 2, 3, 2, 1]);
+
+        // Too short to have any frames between the ends
+        assert_eq!(arrange_frames(vec![0, 1], Playback::PingPong, &[]), vec![0, 1]);
+        assert_eq!(arrange_frames(vec![0], Playback::PingPong, &[]), vec![0]);
+    }
+
+    #[test]
+    fn held_frames() {
+        let hold = |frame, count| Hold {frame, count: steps(count)};
+
+        let holds = [hold(0, 2), hold(2, 3), hold(9, 4)];
+        assert_eq!(arrange_frames(vec![0, 1, 2], Playback::Forward, &holds), vec![0, 0, 1, 2, 2, 2]);
+
+        // Holds index the frames after the playback order is applied
+        let holds = [hold(0, 2), hold(4, 2)];
+        assert_eq!(arrange_frames(vec![0, 1, 2], Playback::Reverse, &holds), vec![2, 2, 1, 0]);
+        assert_eq!(arrange_frames(vec![0, 1, 2], Playback::PingPong, &holds), vec![0, 0, 1, 2, 1]);
+
+        // Holds for the same frame add up
+        let holds = [hold(1, 2), hold(1, 2)];
+        assert_eq!(arrange_frames(vec![0, 1], Playback::Forward, &holds), vec![0, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,216 @@
+use std::thread;
+use std::sync::{Mutex, mpsc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::{Path, PathBuf};
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::renderer::{ThreadRenderContext, ContextCreationError};
+
+use super::{Task, SubmittedTask, TaskError, WeakFileCache, generate_spritesheet_task, generate_pose_task};
+
+/// A description of a task that has not been generated yet
+///
+/// Unlike a `Task`, this does not refer to any opened files, so it can be sent to another thread
+/// and generated there.
+#[derive(Debug, Clone)]
+pub enum TaskSpec {
+    Spritesheet(config::Spritesheet),
+    Pose(config::Pose),
+}
+
+impl TaskSpec {
+    /// Returns the absolute path that the generated task will output to
+    pub fn output_path(&self, base_dir: &Path) -> PathBuf {
+        use TaskSpec::*;
+        match self {
+            Spritesheet(sheet) => sheet.path.resolve(base_dir),
+            Pose(pose) => pose.path.resolve(base_dir),
+        }
+    }
+
+    /// Generates the task, opening any files it needs through the given cache
+    pub fn generate(self, base_dir: &Path, file_cache: &mut WeakFileCache) -> Result<Task, TaskError> {
+        use TaskSpec::*;
+        match self {
+            Spritesheet(sheet) => generate_spritesheet_task(sheet, base_dir, file_cache),
+            Pose(pose) => generate_pose_task(pose, base_dir, file_cache),
+        }
+    }
+}
+
+/// The result of generating and executing a single task
+#[derive(Debug)]
+pub struct TaskOutcome {
+    /// The index of the task in the list of tasks that was executed
+    pub index: usize,
+    /// The time it took to generate and execute the task, not counting any time spent on other
+    /// tasks while it was waiting to be finished
+    pub duration: Duration,
+    pub result: Result<(), TaskError>,
+}
+
+/// Executes tasks using a render context and file cache that belong to a single thread
+///
+/// Files opened by the worker cache GPU resources that belong to its render context, so they are
+/// never shared with other workers.
+///
+/// Each task is finished (read back from the GPU and saved) only after the next task has been
+/// drawn, so the GPU can work on the next image while the previous one is being read.
+pub struct Worker {
+    ctx: ThreadRenderContext,
+    file_cache: WeakFileCache,
+    /// The task that was most recently submitted and has not been finished yet
+    pending: Option<PendingTask>,
+}
+
+/// A task that has been submitted to the GPU by a worker
+struct PendingTask {
+    index: usize,
+    /// The time it took to generate and submit the task
+    ///
+    /// The task is only finished after the next task is submitted, so the time until then can't
+    /// be measured from when the task was started.
+    submit_duration: Duration,
+    task: SubmittedTask,
+}
+
+impl Worker {
+    /// Creates a new worker for the current thread
+    ///
+    /// Only one worker (or `ThreadRenderContext`) should exist per thread.
+    pub fn new() -> Result<Self, ContextCreationError> {
+        Ok(Self {
+            ctx: ThreadRenderContext::new()?,
+            file_cache: WeakFileCache::default(),
+            pending: None,
+        })
+    }
+
+    /// Generates the given task and submits it to the GPU, then finishes the previously submitted
+    /// task
+    ///
+    /// Returns the outcomes of every task that finished, in the order they finished in. Call
+    /// `finish` once there are no more tasks to get the outcome of the last one.
+    pub fn run(&mut self, index: usize, spec: TaskSpec, base_dir: &Path) -> Vec<TaskOutcome> {
+        let start = Instant::now();
+        let submitted = spec.generate(base_dir, &mut self.file_cache)
+            .and_then(|task| task.submit(&mut self.ctx));
+
+        let submit_duration = start.elapsed();
+
+        let mut outcomes: Vec<_> = self.finish().into_iter().collect();
+        match submitted {
+            Ok(task) => self.pending = Some(PendingTask {index, submit_duration, task}),
+            Err(err) => outcomes.push(TaskOutcome {index, duration: submit_duration, result: Err(err)}),
+        }
+
+        outcomes
+    }
+
+    /// Finishes the most recently submitted task, returning its outcome or None if every task has
+    /// already been finished
+    pub fn finish(&mut self) -> Option<TaskOutcome> {
+        let PendingTask {index, submit_duration, task} = self.pending.take()?;
+        let start = Instant::now();
+        let result = task.finish();
+
+        Some(TaskOutcome {index, duration: submit_duration + start.elapsed(), result})
+    }
+}
+
+/// Generates and executes every task using the given number of worker threads
+///
+/// `on_outcome` is called on the current thread as each task finishes, in the order the tasks
+/// finish in. If it returns false, no more tasks will be started, though any tasks that are
+/// already running will still finish and be reported.
+///
+/// With a single job, all tasks are executed on the current thread. This matters on platforms
+/// where an OpenGL context can only be created on the main thread.
+pub fn execute_tasks<F>(
+    specs: Vec<TaskSpec>,
+    base_dir: &Path,
+    jobs: NonZeroUsize,
+    mut on_outcome: F,
+) -> Result<(), ContextCreationError>
+    where F: FnMut(TaskOutcome) -> bool,
+{
+    if jobs.get() == 1 {
+        let mut worker = Worker::new()?;
+        for (index, spec) in specs.into_iter().enumerate() {
+            // Every outcome must be reported, even after one of them asks to stop
+            let mut keep_going = true;
+            for outcome in worker.run(index, spec, base_dir) {
+                keep_going &= on_outcome(outcome);
+            }
+            if !keep_going {
+                break;
+            }
+        }
+        if let Some(outcome) = worker.finish() {
+            on_outcome(outcome);
+        }
+
+        return Ok(());
+    }
+
+    let queue = Mutex::new(specs.into_iter().enumerate());
+    let stopped = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.get() {
+            let sender = sender.clone();
+            let queue = &queue;
+            let stopped = &stopped;
+            scope.spawn(move || {
+                let mut worker = match Worker::new() {
+                    Ok(worker) => worker,
+                    Err(err) => {
+                        // The receiver only goes away once every worker is done
+                        sender.send(Err(err)).expect("bug: outcome receiver was dropped");
+                        return;
+                    },
+                };
+
+                while !stopped.load(Ordering::SeqCst) {
+                    // The lock is only held long enough to take the next task
+                    let next = queue.lock().expect("bug: task queue lock was poisoned").next();
+                    let (index, spec) = match next {
+                        Some(task) => task,
+                        None => break,
+                    };
+
+                    for outcome in worker.run(index, spec, base_dir) {
+                        sender.send(Ok(outcome)).expect("bug: outcome receiver was dropped");
+                    }
+                }
+                if let Some(outcome) = worker.finish() {
+                    sender.send(Ok(outcome)).expect("bug: outcome receiver was dropped");
+                }
+            });
+        }
+        // Only the workers should have senders so that the loop below ends once they are done
+        drop(sender);
+
+        let mut first_error = None;
+        for outcome in receiver {
+            match outcome {
+                Ok(outcome) => if !on_outcome(outcome) {
+                    stopped.store(true, Ordering::SeqCst);
+                },
+
+                Err(err) => {
+                    stopped.store(true, Ordering::SeqCst);
+                    first_error.get_or_insert(err);
+                },
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    })
+}